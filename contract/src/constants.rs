@@ -5,26 +5,51 @@ pub const PREFIX_CONTRACT_NAME: &str = "vesting_contract_hash";
 pub const PREFIX_CONTRACT_VERSION: &str = "vesting_contract_version";
 pub const PREFIX_CONTRACT_PACKAGE_NAME: &str = "vesting_contract_package_hash";
 
+pub const ENTRY_POINT_ACCELERATE: &str = "accelerate";
+pub const ENTRY_POINT_ACCEPT_ADMIN: &str = "accept_admin";
+pub const ENTRY_POINT_ACCEPT_VESTING_ADDRESS: &str = "accept_vesting_address";
+pub const ENTRY_POINT_ADD_VESTING_ADDRESS: &str = "add_vesting_address";
+pub const ENTRY_POINT_ALLOWANCE: &str = "allowance";
 pub const ENTRY_POINT_BALANCE_OF: &str = "balance_of";
+pub const ENTRY_POINT_CANCEL_ADMIN_PROPOSAL: &str = "cancel_admin_proposal";
 pub const ENTRY_POINT_CHANGE_SECURITY: &str = "change_security";
+pub const ENTRY_POINT_CHANGE_VESTING_ADDRESS: &str = "change_vesting_address";
 pub const ENTRY_POINT_CHECK_VESTING_TRANSFER: &str = "check_vesting_transfer";
+pub const ENTRY_POINT_CLAIM: &str = "claim";
+pub const ENTRY_POINT_CREATE_CONTRIBUTOR_GRANT: &str = "create_contributor_grant";
+pub const ENTRY_POINT_CREATE_GRANT: &str = "create_grant";
 pub const ENTRY_POINT_COWL_CEP18_CONTRACT_PACKAGE: &str = "set_cowl_cep18_contract_package";
 pub const ENTRY_POINT_DECIMALS: &str = "decimals";
 pub const ENTRY_POINT_DECREASE_ALLOWANCE: &str = "decrease_allowance";
 pub const ENTRY_POINT_INCREASE_ALLOWANCE: &str = "increase_allowance";
 pub const ENTRY_POINT_INSTALL: &str = "install";
 pub const ENTRY_POINT_MINT: &str = "mint";
+pub const ENTRY_POINT_PAUSE: &str = "pause";
+pub const ENTRY_POINT_PREVIEW_VESTING_TRANSFER: &str = "preview_vesting_transfer";
+pub const ENTRY_POINT_PROPOSE_ADMIN: &str = "propose_admin";
+pub const ENTRY_POINT_RELEASE_DUE: &str = "release_due";
+pub const ENTRY_POINT_RELEASE_MILESTONE: &str = "release_milestone";
+pub const ENTRY_POINT_REMOVE_VESTING_ADDRESS: &str = "remove_vesting_address";
+pub const ENTRY_POINT_REVOKE: &str = "revoke";
 pub const ENTRY_POINT_SET_MODALITIES: &str = "set_modalities";
+pub const ENTRY_POINT_SET_START_TIME: &str = "set_start_time";
 pub const ENTRY_POINT_SET_TRANSFER_FILTER: &str = "set_transfer_filter";
 pub const ENTRY_POINT_TOTAL_SUPPLY: &str = "total_supply";
 pub const ENTRY_POINT_TRANSFER: &str = "transfer";
 pub const ENTRY_POINT_TRANSFER_FROM: &str = "transfer_from";
+pub const ENTRY_POINT_UNPAUSE: &str = "unpause";
 pub const ENTRY_POINT_UPGRADE: &str = "upgrade";
 pub const ENTRY_POINT_VESTING_INFO: &str = "vesting_info";
 pub const ENTRY_POINT_VESTING_STATUS: &str = "vesting_status";
 
+pub const ARG_ACCELERATION_TIME: &str = "acceleration_time";
 pub const ARG_ADDRESS: &str = "address";
+pub const ARG_ADMIN: &str = "admin";
+pub const ARG_ADMIN_COUNT: &str = "admin_count";
 pub const ARG_AMOUNT: &str = "amount";
+pub const ARG_BENEFICIARY: &str = "beneficiary";
+pub const ARG_BENEFICIARIES: &str = "beneficiaries";
+pub const ARG_CLIFF_DURATION: &str = "cliff_duration";
 pub const ARG_COWL_CEP18_CONTRACT_PACKAGE: &str = "cowl_cep18_contract_package";
 pub const ARG_CONTRACT_HASH: &str = "contract_hash";
 pub const ARG_DATA: &str = "data";
@@ -32,43 +57,72 @@ pub const ARG_ENABLE_MINT_BURN: &str = "enable_mint_burn";
 pub const ARG_EVENTS_MODE: &str = "events_mode";
 pub const ARG_FROM: &str = "from";
 pub const ARG_INSTALLER: &str = "installer";
+pub const ARG_MILESTONE_NAME: &str = "milestone_name";
 pub const ARG_NAME: &str = "name";
 pub const ARG_OPERATOR: &str = "operator";
 pub const ARG_OWNER: &str = "owner";
 pub const ARG_PACKAGE_HASH: &str = "package_hash";
 pub const ARG_RECIPIENT: &str = "recipient";
+pub const ARG_REVOCABLE: &str = "revocable";
 pub const ARG_SPENDER: &str = "spender";
+pub const ARG_START_TIME: &str = "start_time";
 pub const ARG_TOTAL_SUPPLY: &str = "total_supply";
 pub const ARG_TO: &str = "to";
 pub const ARG_TRANSFER_FILTER_CONTRACT_PACKAGE: &str = "transfer_filter_contract_package";
 pub const ARG_TRANSFER_FILTER_METHOD: &str = "transfer_filter_method";
+pub const ARG_TREASURY_ADDRESS: &str = "treasury_address";
 pub const ARG_UPGRADE_FLAG: &str = "upgrade";
+pub const ARG_VESTING_ALLOCATIONS: &str = "vesting_allocations";
+pub const ARG_VESTING_ADDRESS: &str = "vesting_address";
+pub const ARG_VESTING_GRANT: &str = "vesting_grant";
+pub const ARG_VESTING_MODE: &str = "vesting_mode";
 pub const ARG_VESTING_TYPE: &str = "vesting_type";
+pub const ARG_VESTING_TYPES: &str = "vesting_types";
 
 pub const DICT_ADDRESSES: &str = "addresses";
+pub const DICT_ADMIN_PROPOSALS: &str = "admin_proposals";
 pub const DICT_ALLOWANCES: &str = "allowances";
 pub const DICT_BALANCES: &str = "balances";
+pub const DICT_PENDING_VESTING_ADDRESSES: &str = "pending_vesting_addresses";
 pub const DICT_SECURITY_BADGES: &str = "security_badges";
 pub const DICT_START_TIME: &str = "start_time";
 pub const DICT_TRANSFERRED_AMOUNT: &str = "transfered_amount";
+pub const DICT_VESTING_ADDRESSES: &str = "vesting_addresses";
 pub const DICT_VESTING_AMOUNT: &str = "vesting_amount";
+pub const DICT_VESTING_GRANTS: &str = "vesting_grants";
 pub const DICT_VESTING_INFO: &str = "vesting_info";
-pub const DICT_VESTING_STATUS: &str = "vesting_status";
+pub const DICT_VESTING_RECORDS: &str = "vesting_records";
+
+// Named key of the pause and acceleration of all vesting types, each vesting type keeps its own
+// in its record
+pub const GLOBAL_VESTING_STATE: &str = "global_vesting_state";
 
 pub const ADMIN_LIST: &str = "admin_list";
+pub const KEEPER_LIST: &str = "keeper_list";
 pub const MINTER_LIST: &str = "minter_list";
 pub const NONE_LIST: &str = "none_list";
+pub const PAUSER_LIST: &str = "pauser_list";
+pub const SCHEDULE_MANAGER_LIST: &str = "schedule_manager_list";
 
 // This is COWL Unit, not the smallest unit with decimal
 pub const COWL_CEP_18_TOKEN_TOTAL_SUPPLY: u64 = 5_500_000_000;
 
+// Allocation shares are expressed in basis points of the total supply
+pub const BASIS_POINTS_DENOMINATOR: u16 = 10_000;
+pub const VESTING_RECORD_VERSION: u8 = 1;
+pub const VESTING_GRANT_RECORD_VERSION: u8 = 1;
+
 // Durations
 pub const HOUR_IN_SECONDS: u64 = 60 * 60;
 pub const YEAR_IN_SECONDS: u64 = 365 * 24 * 60 * 60; // A standard year in seconds
 pub const MONTH_IN_SECONDS: u64 = YEAR_IN_SECONDS / 12; // Approximation for a month
 
+/// Time a nominated admin has to accept the handover
+pub const ADMIN_PROPOSAL_DURATION_IN_SECONDS: u64 = 7 * 24 * HOUR_IN_SECONDS;
+
 const _ONE_HOUR_IN_SECONDS: Duration = Duration::seconds(HOUR_IN_SECONDS as i64);
 const ONE_MONTH_IN_SECONDS: Duration = Duration::seconds(MONTH_IN_SECONDS as i64);
+const THREE_MONTHS_IN_SECONDS: Duration = Duration::seconds(3 * MONTH_IN_SECONDS as i64);
 const ONE_YEAR_IN_SECONDS: Duration = Duration::seconds(YEAR_IN_SECONDS as i64);
 const FOUR_YEARS_IN_SECONDS: Duration = Duration::seconds(4 * YEAR_IN_SECONDS as i64);
 
@@ -80,5 +134,27 @@ pub const DURATION_TREASURY_VESTING: Option<Duration> = Some(FOUR_YEARS_IN_SECON
 pub const DURATION_COMMUNITY_VESTING: Option<Duration> = Some(FOUR_YEARS_IN_SECONDS);
 pub const DURATION_STAKING_VESTING: Option<Duration> = None;
 
-pub const VESTING_PERIOD_IN_SECONDS: Duration = ONE_MONTH_IN_SECONDS;
-// pub const VESTING_PERIOD_IN_SECONDS: Duration = _ONE_HOUR_IN_SECONDS;
+/// Cliff durations for each vesting type, nothing is released before the cliff ends
+pub const CLIFF_LIQUIDITY_VESTING: Option<Duration> = None;
+pub const CLIFF_CONTRIBUTOR_VESTING: Option<Duration> = Some(THREE_MONTHS_IN_SECONDS);
+pub const CLIFF_DEVELOPMENT_VESTING: Option<Duration> = Some(THREE_MONTHS_IN_SECONDS);
+pub const CLIFF_TREASURY_VESTING: Option<Duration> = None;
+pub const CLIFF_COMMUNITY_VESTING: Option<Duration> = None;
+pub const CLIFF_STAKING_VESTING: Option<Duration> = None;
+
+/// Share of each allocation unlocked at start time (TGE), in basis points
+pub const INITIAL_UNLOCK_LIQUIDITY_VESTING: u16 = 0;
+pub const INITIAL_UNLOCK_CONTRIBUTOR_VESTING: u16 = 0;
+pub const INITIAL_UNLOCK_DEVELOPMENT_VESTING: u16 = 0;
+pub const INITIAL_UNLOCK_TREASURY_VESTING: u16 = 0;
+pub const INITIAL_UNLOCK_COMMUNITY_VESTING: u16 = 0;
+pub const INITIAL_UNLOCK_STAKING_VESTING: u16 = 0;
+
+/// Release period for each vesting type, one release amount unlocks at the end of each period
+pub const PERIOD_LIQUIDITY_VESTING: Duration = ONE_MONTH_IN_SECONDS;
+pub const PERIOD_CONTRIBUTOR_VESTING: Duration = ONE_MONTH_IN_SECONDS;
+pub const PERIOD_DEVELOPMENT_VESTING: Duration = ONE_MONTH_IN_SECONDS;
+pub const PERIOD_TREASURY_VESTING: Duration = THREE_MONTHS_IN_SECONDS;
+pub const PERIOD_COMMUNITY_VESTING: Duration = ONE_MONTH_IN_SECONDS;
+pub const PERIOD_STAKING_VESTING: Duration = ONE_MONTH_IN_SECONDS;
+// pub const PERIOD_CONTRIBUTOR_VESTING: Duration = _ONE_HOUR_IN_SECONDS;
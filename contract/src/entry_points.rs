@@ -1,11 +1,21 @@
 //! Contains definition of the entry points.
 use crate::{
     constants::{
-        ADMIN_LIST, ARG_AMOUNT, ARG_CONTRACT_HASH, ARG_COWL_CEP18_CONTRACT_PACKAGE, ARG_DATA,
-        ARG_EVENTS_MODE, ARG_FROM, ARG_OPERATOR, ARG_TO, ARG_VESTING_TYPE,
-        ENTRY_POINT_CHANGE_SECURITY, ENTRY_POINT_CHECK_VESTING_TRANSFER,
-        ENTRY_POINT_COWL_CEP18_CONTRACT_PACKAGE, ENTRY_POINT_INSTALL, ENTRY_POINT_SET_MODALITIES,
-        ENTRY_POINT_UPGRADE, ENTRY_POINT_VESTING_INFO, ENTRY_POINT_VESTING_STATUS, NONE_LIST,
+        ADMIN_LIST, ARG_ACCELERATION_TIME, ARG_ADMIN, ARG_AMOUNT, ARG_BENEFICIARIES,
+        ARG_BENEFICIARY, ARG_CLIFF_DURATION, ARG_CONTRACT_HASH, ARG_COWL_CEP18_CONTRACT_PACKAGE,
+        ARG_DATA, ARG_EVENTS_MODE, ARG_FROM, ARG_MILESTONE_NAME, ARG_OPERATOR, ARG_REVOCABLE,
+        ARG_START_TIME, ARG_TO, ARG_TREASURY_ADDRESS, ARG_VESTING_ADDRESS, ARG_VESTING_GRANT,
+        ARG_VESTING_TYPE, ARG_VESTING_TYPES, ENTRY_POINT_ACCELERATE, ENTRY_POINT_ACCEPT_ADMIN,
+        ENTRY_POINT_ACCEPT_VESTING_ADDRESS, ENTRY_POINT_ADD_VESTING_ADDRESS,
+        ENTRY_POINT_CANCEL_ADMIN_PROPOSAL, ENTRY_POINT_CHANGE_SECURITY,
+        ENTRY_POINT_CHANGE_VESTING_ADDRESS, ENTRY_POINT_CHECK_VESTING_TRANSFER, ENTRY_POINT_CLAIM,
+        ENTRY_POINT_COWL_CEP18_CONTRACT_PACKAGE, ENTRY_POINT_CREATE_CONTRIBUTOR_GRANT,
+        ENTRY_POINT_CREATE_GRANT, ENTRY_POINT_INSTALL, ENTRY_POINT_PAUSE,
+        ENTRY_POINT_PREVIEW_VESTING_TRANSFER, ENTRY_POINT_PROPOSE_ADMIN, ENTRY_POINT_RELEASE_DUE,
+        ENTRY_POINT_RELEASE_MILESTONE, ENTRY_POINT_REMOVE_VESTING_ADDRESS, ENTRY_POINT_REVOKE,
+        ENTRY_POINT_SET_MODALITIES, ENTRY_POINT_SET_START_TIME, ENTRY_POINT_UNPAUSE,
+        ENTRY_POINT_UPGRADE, ENTRY_POINT_VESTING_INFO, ENTRY_POINT_VESTING_STATUS, KEEPER_LIST,
+        NONE_LIST, PAUSER_LIST, SCHEDULE_MANAGER_LIST,
     },
     enums::TransferFilterContractResult,
 };
@@ -29,7 +39,10 @@ pub fn init() -> EntryPoint {
 pub fn upgrade() -> EntryPoint {
     EntryPoint::new(
         ENTRY_POINT_UPGRADE,
-        vec![Parameter::new(ARG_CONTRACT_HASH, CLType::Key)],
+        vec![
+            Parameter::new(ARG_CONTRACT_HASH, CLType::Key),
+            Parameter::new(ADMIN_LIST, CLType::List(Box::new(CLType::Key))),
+        ],
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Contract,
@@ -39,7 +52,10 @@ pub fn upgrade() -> EntryPoint {
 pub fn vesting_status() -> EntryPoint {
     EntryPoint::new(
         String::from(ENTRY_POINT_VESTING_STATUS),
-        vec![Parameter::new(ARG_VESTING_TYPE, CLType::String)],
+        vec![
+            Parameter::new(ARG_VESTING_TYPE, CLType::String),
+            Parameter::new(ARG_BENEFICIARY, CLType::Option(Box::new(CLType::Key))),
+        ],
         Bytes::cl_type(),
         EntryPointAccess::Public,
         EntryPointType::Contract,
@@ -72,10 +88,27 @@ pub fn check_vesting_transfer() -> EntryPoint {
     )
 }
 
+pub fn preview_vesting_transfer() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_PREVIEW_VESTING_TRANSFER,
+        vec![
+            Parameter::new(ARG_FROM, CLType::Key),
+            Parameter::new(ARG_TO, CLType::Key),
+            Parameter::new(ARG_AMOUNT, CLType::U256),
+        ],
+        TransferFilterContractResult::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 pub fn set_modalities() -> EntryPoint {
     EntryPoint::new(
         ENTRY_POINT_SET_MODALITIES,
-        vec![Parameter::new(ARG_EVENTS_MODE, CLType::U8)],
+        vec![
+            Parameter::new(ARG_EVENTS_MODE, CLType::U8),
+            Parameter::new(ARG_TREASURY_ADDRESS, CLType::Option(Box::new(CLType::Key))),
+        ],
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Contract,
@@ -87,6 +120,9 @@ pub fn change_security() -> EntryPoint {
         ENTRY_POINT_CHANGE_SECURITY,
         vec![
             Parameter::new(ADMIN_LIST, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(PAUSER_LIST, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(SCHEDULE_MANAGER_LIST, CLType::List(Box::new(CLType::Key))),
+            Parameter::new(KEEPER_LIST, CLType::List(Box::new(CLType::Key))),
             Parameter::new(NONE_LIST, CLType::List(Box::new(CLType::Key))),
         ],
         CLType::Unit,
@@ -95,6 +131,36 @@ pub fn change_security() -> EntryPoint {
     )
 }
 
+pub fn propose_admin() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_PROPOSE_ADMIN,
+        vec![Parameter::new(ARG_ADMIN, CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn accept_admin() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_ACCEPT_ADMIN,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn cancel_admin_proposal() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_CANCEL_ADMIN_PROPOSAL,
+        vec![Parameter::new(ARG_ADMIN, CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 pub fn set_cowl_cep18_contract_package() -> EntryPoint {
     EntryPoint::new(
         String::from(ENTRY_POINT_COWL_CEP18_CONTRACT_PACKAGE),
@@ -108,16 +174,222 @@ pub fn set_cowl_cep18_contract_package() -> EntryPoint {
     )
 }
 
+pub fn set_start_time() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_SET_START_TIME,
+        vec![
+            Parameter::new(ARG_VESTING_TYPE, CLType::String),
+            Parameter::new(ARG_START_TIME, CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn release_milestone() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_RELEASE_MILESTONE,
+        vec![
+            Parameter::new(ARG_VESTING_TYPE, CLType::String),
+            Parameter::new(ARG_MILESTONE_NAME, CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn create_grant() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_CREATE_GRANT,
+        vec![Parameter::new(ARG_VESTING_GRANT, Bytes::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn create_contributor_grant() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_CREATE_CONTRIBUTOR_GRANT,
+        vec![
+            Parameter::new(ARG_BENEFICIARY, CLType::Key),
+            Parameter::new(ARG_AMOUNT, CLType::U256),
+            Parameter::new(ARG_START_TIME, CLType::Option(Box::new(CLType::U64))),
+            Parameter::new(ARG_CLIFF_DURATION, CLType::Option(Box::new(CLType::U64))),
+            Parameter::new(ARG_REVOCABLE, CLType::Option(Box::new(CLType::Bool))),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn change_vesting_address() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_CHANGE_VESTING_ADDRESS,
+        vec![
+            Parameter::new(ARG_VESTING_TYPE, CLType::String),
+            Parameter::new(ARG_VESTING_ADDRESS, CLType::Key),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn accept_vesting_address() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_ACCEPT_VESTING_ADDRESS,
+        vec![Parameter::new(ARG_VESTING_TYPE, CLType::String)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn add_vesting_address() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_ADD_VESTING_ADDRESS,
+        vec![
+            Parameter::new(ARG_VESTING_TYPE, CLType::String),
+            Parameter::new(ARG_VESTING_ADDRESS, CLType::Key),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn remove_vesting_address() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_REMOVE_VESTING_ADDRESS,
+        vec![
+            Parameter::new(ARG_VESTING_TYPE, CLType::String),
+            Parameter::new(ARG_VESTING_ADDRESS, CLType::Key),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn claim() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_CLAIM,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn release_due() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_RELEASE_DUE,
+        vec![
+            Parameter::new(
+                ARG_VESTING_TYPES,
+                CLType::Option(Box::new(CLType::List(Box::new(CLType::String)))),
+            ),
+            Parameter::new(
+                ARG_BENEFICIARIES,
+                CLType::Option(Box::new(CLType::List(Box::new(CLType::Key)))),
+            ),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn revoke() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_REVOKE,
+        vec![Parameter::new(ARG_BENEFICIARY, CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn pause() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_PAUSE,
+        vec![Parameter::new(
+            ARG_VESTING_TYPE,
+            CLType::Option(Box::new(CLType::String)),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn unpause() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_UNPAUSE,
+        vec![Parameter::new(
+            ARG_VESTING_TYPE,
+            CLType::Option(Box::new(CLType::String)),
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn accelerate() -> EntryPoint {
+    EntryPoint::new(
+        ENTRY_POINT_ACCELERATE,
+        vec![
+            Parameter::new(ARG_VESTING_TYPE, CLType::Option(Box::new(CLType::String))),
+            Parameter::new(ARG_ACCELERATION_TIME, CLType::Option(Box::new(CLType::U64))),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 pub fn generate_entry_points() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
+    // Installer, once
     entry_points.add_entry_point(init());
+    // Admin
     entry_points.add_entry_point(upgrade());
-    entry_points.add_entry_point(vesting_status());
-    entry_points.add_entry_point(vesting_info());
-    entry_points.add_entry_point(check_vesting_transfer());
     entry_points.add_entry_point(set_modalities());
     entry_points.add_entry_point(change_security());
+    entry_points.add_entry_point(propose_admin());
+    entry_points.add_entry_point(cancel_admin_proposal());
     entry_points.add_entry_point(set_cowl_cep18_contract_package());
+    entry_points.add_entry_point(revoke());
+    entry_points.add_entry_point(accelerate());
+    entry_points.add_entry_point(release_milestone());
+    entry_points.add_entry_point(create_grant());
+    entry_points.add_entry_point(create_contributor_grant());
+    entry_points.add_entry_point(change_vesting_address());
+    entry_points.add_entry_point(add_vesting_address());
+    entry_points.add_entry_point(remove_vesting_address());
+    // Pauser
+    entry_points.add_entry_point(pause());
+    entry_points.add_entry_point(unpause());
+    // ScheduleManager
+    entry_points.add_entry_point(set_start_time());
+    // Keeper
+    entry_points.add_entry_point(release_due());
+    // COWL CEP-18 contract package
+    entry_points.add_entry_point(check_vesting_transfer());
+    // Nominated admin, vesting address or beneficiary
+    entry_points.add_entry_point(accept_admin());
+    entry_points.add_entry_point(accept_vesting_address());
+    entry_points.add_entry_point(claim());
+    // Anyone, refreshes the stored status it returns
+    entry_points.add_entry_point(vesting_status());
+    // Anyone, read-only
+    entry_points.add_entry_point(vesting_info());
+    entry_points.add_entry_point(preview_vesting_transfer());
 
     entry_points
 }
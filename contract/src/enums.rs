@@ -1,7 +1,14 @@
 use crate::{
     constants::{
+        CLIFF_COMMUNITY_VESTING, CLIFF_CONTRIBUTOR_VESTING, CLIFF_DEVELOPMENT_VESTING,
+        CLIFF_LIQUIDITY_VESTING, CLIFF_STAKING_VESTING, CLIFF_TREASURY_VESTING,
         DURATION_COMMUNITY_VESTING, DURATION_CONTRIBUTOR_VESTING, DURATION_DEVELOPMENT_VESTING,
         DURATION_LIQUIDITY_VESTING, DURATION_STAKING_VESTING, DURATION_TREASURY_VESTING,
+        INITIAL_UNLOCK_COMMUNITY_VESTING, INITIAL_UNLOCK_CONTRIBUTOR_VESTING,
+        INITIAL_UNLOCK_DEVELOPMENT_VESTING, INITIAL_UNLOCK_LIQUIDITY_VESTING,
+        INITIAL_UNLOCK_STAKING_VESTING, INITIAL_UNLOCK_TREASURY_VESTING, PERIOD_COMMUNITY_VESTING,
+        PERIOD_CONTRIBUTOR_VESTING, PERIOD_DEVELOPMENT_VESTING, PERIOD_LIQUIDITY_VESTING,
+        PERIOD_STAKING_VESTING, PERIOD_TREASURY_VESTING,
     },
     error::VestingError,
     vesting::VestingInfo,
@@ -35,6 +42,29 @@ impl TryFrom<u8> for EventsMode {
     }
 }
 
+/// Where the allocations are held once installed
+#[repr(u8)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum VestingMode {
+    /// Allocations are transferred to the vesting addresses and locked by the transfer filter
+    #[default]
+    Transfer = 0,
+    /// Allocations stay with the vesting contract package until claimed
+    Custody = 1,
+}
+
+impl TryFrom<u8> for VestingMode {
+    type Error = VestingError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(VestingMode::Transfer),
+            1 => Ok(VestingMode::Custody),
+            _ => Err(VestingError::InvalidVestingMode),
+        }
+    }
+}
+
 #[repr(C)]
 #[non_exhaustive]
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy, EnumIter)]
@@ -122,36 +152,174 @@ impl fmt::Display for VestingType {
     }
 }
 
+/// How release periods are measured: fixed-length periods of `vesting_period` seconds, or
+/// calendar months released on the same day-of-month as the start time
+#[repr(u8)]
+#[non_exhaustive]
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy, Default)]
+pub enum PeriodMode {
+    #[default]
+    Fixed = 0,
+    Calendar = 1,
+}
+
+impl ToBytes for PeriodMode {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let bytes = vec![*self as u8];
+        Ok(bytes)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for PeriodMode {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        match bytes.split_first() {
+            None => Err(Error::EarlyEndOfStream),
+            Some((byte, rem)) => {
+                let period_mode = PeriodMode::try_from(*byte).map_err(|_| Error::Formatting)?;
+                Ok((period_mode, rem))
+            }
+        }
+    }
+}
+
+impl TryFrom<u8> for PeriodMode {
+    type Error = VestingError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(PeriodMode::Fixed),
+            1 => Ok(PeriodMode::Calendar),
+            _ => Err(VestingError::InvalidPeriodMode),
+        }
+    }
+}
+
+/// Shape of the release schedule between start (or cliff end) and the end of the vesting:
+/// `Stepwise` releases an equal amount every period, `Continuous` accrues linearly every second
+/// and `BackLoaded` releases quadratically more in later periods. For continuous and back-loaded
+/// curves `release_amount_per_period` reports the average release. `Tranches` follows the
+/// tranche list stored for the vesting type, releasing the next tranche at each unlock date.
+/// `Milestones` is not time based, each milestone share vests once an admin releases it.
+#[repr(u8)]
+#[non_exhaustive]
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Copy, Default)]
+pub enum ReleaseCurve {
+    #[default]
+    Stepwise = 0,
+    Continuous = 1,
+    BackLoaded = 2,
+    Tranches = 3,
+    Milestones = 4,
+}
+
+impl ToBytes for ReleaseCurve {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let bytes = vec![*self as u8];
+        Ok(bytes)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for ReleaseCurve {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        match bytes.split_first() {
+            None => Err(Error::EarlyEndOfStream),
+            Some((byte, rem)) => {
+                let release_curve = ReleaseCurve::try_from(*byte).map_err(|_| Error::Formatting)?;
+                Ok((release_curve, rem))
+            }
+        }
+    }
+}
+
+impl TryFrom<u8> for ReleaseCurve {
+    type Error = VestingError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ReleaseCurve::Stepwise),
+            1 => Ok(ReleaseCurve::Continuous),
+            2 => Ok(ReleaseCurve::BackLoaded),
+            3 => Ok(ReleaseCurve::Tranches),
+            4 => Ok(ReleaseCurve::Milestones),
+            _ => Err(VestingError::InvalidReleaseCurve),
+        }
+    }
+}
+
 pub const VESTING_INFO: &[VestingInfo] = &[
     VestingInfo {
         vesting_type: VestingType::Treasury,
         maybe_vesting_address_key: None,
+        vesting_address_keys: Vec::new(),
         vesting_duration: DURATION_TREASURY_VESTING,
+        cliff_duration: CLIFF_TREASURY_VESTING,
+        vesting_period: PERIOD_TREASURY_VESTING,
+        period_mode: PeriodMode::Fixed,
+        release_curve: ReleaseCurve::Stepwise,
+        initial_unlock_share: INITIAL_UNLOCK_TREASURY_VESTING,
     },
     VestingInfo {
         vesting_type: VestingType::Contributor,
         maybe_vesting_address_key: None,
+        vesting_address_keys: Vec::new(),
         vesting_duration: DURATION_CONTRIBUTOR_VESTING,
+        cliff_duration: CLIFF_CONTRIBUTOR_VESTING,
+        vesting_period: PERIOD_CONTRIBUTOR_VESTING,
+        period_mode: PeriodMode::Fixed,
+        release_curve: ReleaseCurve::Stepwise,
+        initial_unlock_share: INITIAL_UNLOCK_CONTRIBUTOR_VESTING,
     },
     VestingInfo {
         vesting_type: VestingType::Development,
         maybe_vesting_address_key: None,
+        vesting_address_keys: Vec::new(),
         vesting_duration: DURATION_DEVELOPMENT_VESTING,
+        cliff_duration: CLIFF_DEVELOPMENT_VESTING,
+        vesting_period: PERIOD_DEVELOPMENT_VESTING,
+        period_mode: PeriodMode::Fixed,
+        release_curve: ReleaseCurve::Stepwise,
+        initial_unlock_share: INITIAL_UNLOCK_DEVELOPMENT_VESTING,
     },
     VestingInfo {
         vesting_type: VestingType::Liquidity,
         maybe_vesting_address_key: None,
+        vesting_address_keys: Vec::new(),
         vesting_duration: DURATION_LIQUIDITY_VESTING,
+        cliff_duration: CLIFF_LIQUIDITY_VESTING,
+        vesting_period: PERIOD_LIQUIDITY_VESTING,
+        period_mode: PeriodMode::Fixed,
+        release_curve: ReleaseCurve::Stepwise,
+        initial_unlock_share: INITIAL_UNLOCK_LIQUIDITY_VESTING,
     },
     VestingInfo {
         vesting_type: VestingType::Community,
         maybe_vesting_address_key: None,
+        vesting_address_keys: Vec::new(),
         vesting_duration: DURATION_COMMUNITY_VESTING,
+        cliff_duration: CLIFF_COMMUNITY_VESTING,
+        vesting_period: PERIOD_COMMUNITY_VESTING,
+        period_mode: PeriodMode::Fixed,
+        release_curve: ReleaseCurve::Stepwise,
+        initial_unlock_share: INITIAL_UNLOCK_COMMUNITY_VESTING,
     },
     VestingInfo {
         vesting_type: VestingType::Staking,
         maybe_vesting_address_key: None,
+        vesting_address_keys: Vec::new(),
         vesting_duration: DURATION_STAKING_VESTING,
+        cliff_duration: CLIFF_STAKING_VESTING,
+        vesting_period: PERIOD_STAKING_VESTING,
+        period_mode: PeriodMode::Fixed,
+        release_curve: ReleaseCurve::Stepwise,
+        initial_unlock_share: INITIAL_UNLOCK_STAKING_VESTING,
     },
 ];
 
@@ -163,15 +331,35 @@ pub fn get_vesting_duration(vesting_type: VestingType) -> Option<Duration> {
         .and_then(|vesting_info| vesting_info.vesting_duration)
 }
 
-pub const VESTING_PERCENTAGES: &[(VestingType, u8)] = &[
-    (VestingType::Liquidity, 20),
-    (VestingType::Contributor, 10),
-    (VestingType::Development, 12),
-    (VestingType::Treasury, 30),
-    (VestingType::Community, 28),
+/// Function to get the cliff duration for a specific vesting type
+pub fn get_cliff_duration(vesting_type: VestingType) -> Option<Duration> {
+    VESTING_INFO
+        .iter()
+        .find(|vesting_info| vesting_info.vesting_type == vesting_type)
+        .and_then(|vesting_info| vesting_info.cliff_duration)
+}
+
+/// Function to get the release period for a specific vesting type
+pub fn get_vesting_period(vesting_type: VestingType) -> Option<Duration> {
+    VESTING_INFO
+        .iter()
+        .find(|vesting_info| vesting_info.vesting_type == vesting_type)
+        .map(|vesting_info| vesting_info.vesting_period)
+}
+
+/// Share of the total supply for each vesting type, in basis points
+pub const VESTING_SHARES: &[(VestingType, u16)] = &[
+    (VestingType::Liquidity, 2_000),
+    (VestingType::Contributor, 1_000),
+    (VestingType::Development, 1_200),
+    (VestingType::Treasury, 3_000),
+    (VestingType::Community, 2_800),
     (VestingType::Staking, 0),
 ];
 
+/// Vesting type receiving the rounding remainder of the allocations
+pub const VESTING_DUST_RECIPIENT: VestingType = VestingType::Treasury;
+
 #[repr(u8)]
 #[non_exhaustive]
 #[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
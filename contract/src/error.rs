@@ -42,6 +42,55 @@ pub enum VestingError {
     MissingVestingType = 40003,
     InvalidInstallerTotalSupply = 40004,
     InvalidRecepientAllocation = 40005,
+    InvalidVestingAllocations = 40006,
+    InvalidVestingAllocationsTotal = 40007,
+    InvalidPeriodMode = 40008,
+    InvalidReleaseCurve = 40009,
+    InvalidVestingTranches = 40010,
+    InvalidStartTime = 40011,
+    MissingStartTime = 40012,
+    VestingAlreadyStarted = 40013,
+    InvalidVestingMilestones = 40014,
+    MissingMilestoneName = 40015,
+    InvalidMilestoneName = 40016,
+    MilestoneAlreadyReleased = 40017,
+    InvalidVestingGrant = 40018,
+    MissingVestingGrant = 40019,
+    VestingGrantAlreadyExists = 40020,
+    InsufficientVestingAmount = 40021,
+    InvalidBeneficiary = 40022,
+    MissingBeneficiary = 40023,
+    InvalidAmount = 40024,
+    MissingAmount = 40025,
+    InvalidCliffDuration = 40026,
+    InvalidVestingAddress = 40027,
+    MissingVestingAddress = 40028,
+    MissingPendingVestingAddress = 40029,
+    VestingAddressNotEmpty = 40030,
+    InvalidVestingMode = 40031,
+    NothingToClaim = 40032,
+    MissingTreasuryAddress = 40033,
+    InvalidTreasuryAddress = 40034,
+    VestingGrantNotRevocable = 40035,
+    VestingGrantRevoked = 40036,
+    VestingPaused = 40037,
+    VestingAlreadyPaused = 40038,
+    VestingNotPaused = 40039,
+    VestingAlreadyAccelerated = 40040,
+    InvalidTransferFilterCaller = 40041,
+    LastAdminRemoval = 40042,
+    InvalidAdminCount = 40043,
+    MissingAdminCount = 40044,
+    MissingAdminProposal = 40045,
+    AdminProposalExpired = 40046,
+    InvalidPauserList = 40047,
+    InvalidScheduleManagerList = 40048,
+    InvalidKeeperList = 40049,
+    AdminGrantRequiresProposal = 40050,
+    NotMilestoneSchedule = 40051,
+    AlreadyAdmin = 40052,
+    RevocableGrantRequiresCustody = 40053,
+    InvalidAccelerationTime = 40054,
 }
 
 impl From<VestingError> for ApiError {
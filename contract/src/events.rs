@@ -2,11 +2,12 @@ use crate::security::SecurityBadge;
 #[cfg(feature = "contract-support")]
 use crate::{constants::ARG_EVENTS_MODE, enums::EventsMode, utils::get_stored_value};
 use alloc::collections::btree_map::BTreeMap;
+use alloc::string::String;
 #[cfg(feature = "contract-support")]
 use casper_contract::unwrap_or_revert::UnwrapOrRevert;
-use casper_event_standard::Event;
 #[cfg(feature = "contract-support")]
-use casper_event_standard::{emit, Schemas};
+use casper_event_standard::emit;
+use casper_event_standard::{Event, Schemas};
 use casper_types::{bytesrepr::Bytes, Key, U256};
 #[cfg(feature = "contract-support")]
 use core::convert::TryFrom;
@@ -18,6 +19,22 @@ pub enum Event {
     Upgrade(Upgrade),
     CheckTransfer(CheckTransfer),
     CowlCep18ContractPackageUpdate(CowlCep18ContractPackageUpdate),
+    StartTimeUpdate(StartTimeUpdate),
+    MilestoneRelease(MilestoneRelease),
+    GrantCreation(GrantCreation),
+    VestingAddressProposal(VestingAddressProposal),
+    VestingAddressChange(VestingAddressChange),
+    VestingAddressAddition(VestingAddressAddition),
+    VestingAddressRemoval(VestingAddressRemoval),
+    VestingRelease(VestingRelease),
+    VestingReleaseSkip(VestingReleaseSkip),
+    GrantRevocation(GrantRevocation),
+    VestingPause(VestingPause),
+    VestingUnpause(VestingUnpause),
+    VestingAcceleration(VestingAcceleration),
+    AdminProposal(AdminProposal),
+    AdminAcceptance(AdminAcceptance),
+    AdminProposalCancellation(AdminProposalCancellation),
 }
 
 #[cfg(feature = "contract-support")]
@@ -100,6 +117,295 @@ impl CowlCep18ContractPackageUpdate {
     }
 }
 
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct StartTimeUpdate {
+    pub key: Key,
+    pub vesting_type: String,
+    pub previous_start_time: u64,
+    pub start_time: u64,
+}
+
+impl StartTimeUpdate {
+    pub fn new(key: Key, vesting_type: String, previous_start_time: u64, start_time: u64) -> Self {
+        Self {
+            key,
+            vesting_type,
+            previous_start_time,
+            start_time,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct MilestoneRelease {
+    pub attester: Key,
+    pub vesting_type: String,
+    pub milestone_name: String,
+    /// Released share of the scheduled amount in basis points
+    pub share: u32,
+}
+
+impl MilestoneRelease {
+    pub fn new(attester: Key, vesting_type: String, milestone_name: String, share: u32) -> Self {
+        Self {
+            attester,
+            vesting_type,
+            milestone_name,
+            share,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct GrantCreation {
+    pub key: Key,
+    pub beneficiary: Key,
+    pub funding_type: String,
+    pub amount: U256,
+    pub start_time: u64,
+}
+
+impl GrantCreation {
+    pub fn new(
+        key: Key,
+        beneficiary: Key,
+        funding_type: String,
+        amount: U256,
+        start_time: u64,
+    ) -> Self {
+        Self {
+            key,
+            beneficiary,
+            funding_type,
+            amount,
+            start_time,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct VestingAddressProposal {
+    pub key: Key,
+    pub vesting_type: String,
+    pub vesting_address: Key,
+}
+
+impl VestingAddressProposal {
+    pub fn new(key: Key, vesting_type: String, vesting_address: Key) -> Self {
+        Self {
+            key,
+            vesting_type,
+            vesting_address,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct VestingAddressChange {
+    pub vesting_type: String,
+    pub previous_address: Key,
+    pub new_address: Key,
+    /// Part of the previous address balance moved to the new address
+    pub moved_amount: U256,
+}
+
+impl VestingAddressChange {
+    pub fn new(
+        vesting_type: String,
+        previous_address: Key,
+        new_address: Key,
+        moved_amount: U256,
+    ) -> Self {
+        Self {
+            vesting_type,
+            previous_address,
+            new_address,
+            moved_amount,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct VestingAddressAddition {
+    pub key: Key,
+    pub vesting_type: String,
+    pub vesting_address: Key,
+}
+
+impl VestingAddressAddition {
+    pub fn new(key: Key, vesting_type: String, vesting_address: Key) -> Self {
+        Self {
+            key,
+            vesting_type,
+            vesting_address,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct VestingAddressRemoval {
+    pub key: Key,
+    pub vesting_type: String,
+    pub vesting_address: Key,
+}
+
+impl VestingAddressRemoval {
+    pub fn new(key: Key, vesting_type: String, vesting_address: Key) -> Self {
+        Self {
+            key,
+            vesting_type,
+            vesting_address,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct VestingRelease {
+    pub recipient: Key,
+    pub vesting_type: String,
+    pub amount: U256,
+}
+
+impl VestingRelease {
+    pub fn new(recipient: Key, vesting_type: String, amount: U256) -> Self {
+        Self {
+            recipient,
+            vesting_type,
+            amount,
+        }
+    }
+}
+
+/// Release pushed by a keeper that was left for a later run, the schedule being paused
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct VestingReleaseSkip {
+    pub recipient: Key,
+    pub vesting_type: String,
+}
+
+impl VestingReleaseSkip {
+    pub fn new(recipient: Key, vesting_type: String) -> Self {
+        Self {
+            recipient,
+            vesting_type,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct GrantRevocation {
+    pub key: Key,
+    pub beneficiary: Key,
+    pub vested_amount: U256,
+    pub revoked_amount: U256,
+    pub treasury_address: Key,
+    pub revoke_time: u64,
+}
+
+impl GrantRevocation {
+    pub fn new(
+        key: Key,
+        beneficiary: Key,
+        vested_amount: U256,
+        revoked_amount: U256,
+        treasury_address: Key,
+        revoke_time: u64,
+    ) -> Self {
+        Self {
+            key,
+            beneficiary,
+            vested_amount,
+            revoked_amount,
+            treasury_address,
+            revoke_time,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct VestingPause {
+    pub key: Key,
+    /// Paused vesting type, all vesting types if none
+    pub vesting_type: Option<String>,
+}
+
+impl VestingPause {
+    pub fn new(key: Key, vesting_type: Option<String>) -> Self {
+        Self { key, vesting_type }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct VestingUnpause {
+    pub key: Key,
+    /// Unpaused vesting type, all vesting types if none
+    pub vesting_type: Option<String>,
+}
+
+impl VestingUnpause {
+    pub fn new(key: Key, vesting_type: Option<String>) -> Self {
+        Self { key, vesting_type }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct VestingAcceleration {
+    pub key: Key,
+    /// Accelerated vesting type, all vesting types if none
+    pub vesting_type: Option<String>,
+    pub acceleration_time: u64,
+}
+
+impl VestingAcceleration {
+    pub fn new(key: Key, vesting_type: Option<String>, acceleration_time: u64) -> Self {
+        Self {
+            key,
+            vesting_type,
+            acceleration_time,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AdminProposal {
+    pub key: Key,
+    pub admin: Key,
+    pub expiry_time: u64,
+}
+
+impl AdminProposal {
+    pub fn new(key: Key, admin: Key, expiry_time: u64) -> Self {
+        Self {
+            key,
+            admin,
+            expiry_time,
+        }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AdminAcceptance {
+    pub admin: Key,
+}
+
+impl AdminAcceptance {
+    pub fn new(admin: Key) -> Self {
+        Self { admin }
+    }
+}
+
+#[derive(Event, Debug, PartialEq, Eq)]
+pub struct AdminProposalCancellation {
+    pub key: Key,
+    pub admin: Key,
+}
+
+impl AdminProposalCancellation {
+    pub fn new(key: Key, admin: Key) -> Self {
+        Self { key, admin }
+    }
+}
+
 #[cfg(feature = "contract-support")]
 fn ces(event: Event) {
     match event {
@@ -108,25 +414,70 @@ fn ces(event: Event) {
         Event::ChangeSecurity(ev) => emit(ev),
         Event::CowlCep18ContractPackageUpdate(ev) => emit(ev),
         Event::CheckTransfer(ev) => emit(ev),
+        Event::StartTimeUpdate(ev) => emit(ev),
+        Event::MilestoneRelease(ev) => emit(ev),
+        Event::GrantCreation(ev) => emit(ev),
+        Event::VestingAddressProposal(ev) => emit(ev),
+        Event::VestingAddressChange(ev) => emit(ev),
+        Event::VestingAddressAddition(ev) => emit(ev),
+        Event::VestingAddressRemoval(ev) => emit(ev),
+        Event::VestingRelease(ev) => emit(ev),
+        Event::VestingReleaseSkip(ev) => emit(ev),
+        Event::GrantRevocation(ev) => emit(ev),
+        Event::VestingPause(ev) => emit(ev),
+        Event::VestingUnpause(ev) => emit(ev),
+        Event::VestingAcceleration(ev) => emit(ev),
+        Event::AdminProposal(ev) => emit(ev),
+        Event::AdminAcceptance(ev) => emit(ev),
+        Event::AdminProposalCancellation(ev) => emit(ev),
     }
 }
 
+/// Schemas of every event the contract emits
+pub fn get_event_schemas() -> Schemas {
+    Schemas::new()
+        .with::<SetModalities>()
+        .with::<Upgrade>()
+        .with::<CowlCep18ContractPackageUpdate>()
+        .with::<CheckTransfer>()
+        .with::<ChangeSecurity>()
+        .with::<StartTimeUpdate>()
+        .with::<MilestoneRelease>()
+        .with::<GrantCreation>()
+        .with::<VestingAddressProposal>()
+        .with::<VestingAddressChange>()
+        .with::<VestingAddressAddition>()
+        .with::<VestingAddressRemoval>()
+        .with::<VestingRelease>()
+        .with::<VestingReleaseSkip>()
+        .with::<GrantRevocation>()
+        .with::<VestingPause>()
+        .with::<VestingUnpause>()
+        .with::<VestingAcceleration>()
+        .with::<AdminProposal>()
+        .with::<AdminAcceptance>()
+        .with::<AdminProposalCancellation>()
+}
+
+/// Initializes the CES structures, or overwrites the stored schemas when they already exist, so
+/// a contract upgraded from an earlier release also describes the events added since
 #[cfg(feature = "contract-support")]
 pub fn init_events() {
-    use casper_contract::contract_api::runtime::get_key;
+    use casper_contract::contract_api::{runtime::get_key, storage};
 
     let events_mode =
         EventsMode::try_from(get_stored_value::<u8>(ARG_EVENTS_MODE)).unwrap_or_revert();
 
-    if [EventsMode::CES].contains(&events_mode)
-        && get_key(casper_event_standard::EVENTS_DICT).is_none()
-    {
-        let schemas = Schemas::new()
-            .with::<SetModalities>()
-            .with::<Upgrade>()
-            .with::<CowlCep18ContractPackageUpdate>()
-            .with::<CheckTransfer>()
-            .with::<ChangeSecurity>();
-        casper_event_standard::init(schemas);
+    if ![EventsMode::CES].contains(&events_mode) {
+        return;
+    }
+
+    if get_key(casper_event_standard::EVENTS_DICT).is_none() {
+        casper_event_standard::init(get_event_schemas());
+    } else if let Some(schemas_key) = get_key(casper_event_standard::EVENTS_SCHEMA) {
+        storage::write(
+            schemas_key.into_uref().unwrap_or_revert(),
+            get_event_schemas(),
+        );
     }
 }
@@ -25,39 +25,70 @@ use casper_types::{
 };
 use cowl_vesting::{
     constants::{
-        ADMIN_LIST, ARG_AMOUNT, ARG_CONTRACT_HASH, ARG_COWL_CEP18_CONTRACT_PACKAGE, ARG_DATA,
-        ARG_EVENTS_MODE, ARG_FROM, ARG_INSTALLER, ARG_NAME, ARG_OPERATOR, ARG_OWNER,
-        ARG_PACKAGE_HASH, ARG_RECIPIENT, ARG_TO, ARG_TRANSFER_FILTER_CONTRACT_PACKAGE,
-        ARG_TRANSFER_FILTER_METHOD, ARG_UPGRADE_FLAG, ARG_VESTING_TYPE,
-        COWL_CEP_18_TOKEN_TOTAL_SUPPLY, DICT_ADDRESSES, DICT_SECURITY_BADGES, DICT_START_TIME,
-        DICT_TRANSFERRED_AMOUNT, DICT_VESTING_AMOUNT, DICT_VESTING_INFO, DICT_VESTING_STATUS,
-        ENTRY_POINT_CHANGE_SECURITY, ENTRY_POINT_CHECK_VESTING_TRANSFER, ENTRY_POINT_DECIMALS,
-        ENTRY_POINT_INSTALL, ENTRY_POINT_MINT, ENTRY_POINT_SET_TRANSFER_FILTER,
-        ENTRY_POINT_TOTAL_SUPPLY, ENTRY_POINT_TRANSFER, ENTRY_POINT_UPGRADE, MINTER_LIST,
-        NONE_LIST, PREFIX_ACCESS_KEY_NAME, PREFIX_CONTRACT_NAME, PREFIX_CONTRACT_PACKAGE_NAME,
-        PREFIX_CONTRACT_VERSION,
+        ADMIN_LIST, ADMIN_PROPOSAL_DURATION_IN_SECONDS, ARG_ACCELERATION_TIME, ARG_ADMIN,
+        ARG_ADMIN_COUNT, ARG_AMOUNT, ARG_BENEFICIARIES, ARG_BENEFICIARY, ARG_CLIFF_DURATION,
+        ARG_CONTRACT_HASH, ARG_COWL_CEP18_CONTRACT_PACKAGE, ARG_DATA, ARG_EVENTS_MODE, ARG_FROM,
+        ARG_INSTALLER, ARG_MILESTONE_NAME, ARG_NAME, ARG_OPERATOR, ARG_OWNER, ARG_PACKAGE_HASH,
+        ARG_RECIPIENT, ARG_REVOCABLE, ARG_START_TIME, ARG_TO, ARG_TRANSFER_FILTER_CONTRACT_PACKAGE,
+        ARG_TRANSFER_FILTER_METHOD, ARG_TREASURY_ADDRESS, ARG_UPGRADE_FLAG, ARG_VESTING_ADDRESS,
+        ARG_VESTING_ALLOCATIONS, ARG_VESTING_GRANT, ARG_VESTING_MODE, ARG_VESTING_TYPE,
+        ARG_VESTING_TYPES, COWL_CEP_18_TOKEN_TOTAL_SUPPLY, DICT_ADMIN_PROPOSALS,
+        DICT_PENDING_VESTING_ADDRESSES, DICT_SECURITY_BADGES, DICT_VESTING_ADDRESSES,
+        DICT_VESTING_GRANTS, DICT_VESTING_RECORDS, ENTRY_POINT_CHANGE_SECURITY,
+        ENTRY_POINT_CHECK_VESTING_TRANSFER, ENTRY_POINT_DECIMALS, ENTRY_POINT_INSTALL,
+        ENTRY_POINT_MINT, ENTRY_POINT_SET_TRANSFER_FILTER, ENTRY_POINT_TOTAL_SUPPLY,
+        ENTRY_POINT_TRANSFER, ENTRY_POINT_UPGRADE, KEEPER_LIST, MINTER_LIST, NONE_LIST,
+        PAUSER_LIST, PREFIX_ACCESS_KEY_NAME, PREFIX_CONTRACT_NAME, PREFIX_CONTRACT_PACKAGE_NAME,
+        PREFIX_CONTRACT_VERSION, SCHEDULE_MANAGER_LIST,
     },
     entry_points::generate_entry_points,
-    enums::{EventsMode, TransferFilterContractResult, VestingType, VESTING_INFO},
+    enums::{EventsMode, TransferFilterContractResult, VestingMode, VestingType},
     error::VestingError,
     events::{
-        init_events, record_event_dictionary, ChangeSecurity, CheckTransfer,
-        CowlCep18ContractPackageUpdate, Event, SetModalities, Upgrade,
+        init_events, record_event_dictionary, AdminAcceptance, AdminProposal,
+        AdminProposalCancellation, ChangeSecurity, CheckTransfer, CowlCep18ContractPackageUpdate,
+        Event, GrantCreation, GrantRevocation, MilestoneRelease, SetModalities, StartTimeUpdate,
+        Upgrade, VestingAcceleration, VestingAddressAddition, VestingAddressChange,
+        VestingAddressProposal, VestingAddressRemoval, VestingPause, VestingRelease,
+        VestingReleaseSkip, VestingUnpause,
+    },
+    security::{
+        change_sec_badge, get_admin_proposal, get_security_badge, init_admin_count, sec_check,
+        set_admin_proposal, SecurityBadge,
     },
-    security::{change_sec_badge, sec_check, SecurityBadge},
     utils::{
-        get_cowl_cep18_contract_package_hash, get_named_arg_with_user_errors,
-        get_optional_named_arg_with_user_errors, get_stored_value_with_user_errors,
-        get_verified_caller, set_dictionary_value_for_key,
+        get_cowl_cep18_contract_package_hash, get_dictionary_value_from_key,
+        get_named_arg_with_user_errors, get_optional_named_arg_with_user_errors,
+        get_stored_value_with_user_errors, get_verified_caller, set_dictionary_value_for_key,
     },
     vesting::{
-        calculate_vesting_allocations, get_current_balance_for_key, get_vesting_info,
-        get_vesting_transfer, ret_vesting_info, ret_vesting_status, update_vesting_status,
+        accelerate_vesting, add_shared_vesting_address, calculate_vesting_allocations,
+        claim_vesting_amount, create_contributor_vesting_grant, create_vesting_grant,
+        get_current_balance_for_key, get_default_vesting_configs, get_global_vesting_state,
+        get_treasury_address, get_vesting_info, get_vesting_mode, get_vesting_record,
+        get_vesting_transfer, is_vesting_address, migrate_vesting_records, move_approved_balance,
+        new_vesting_record, refresh_vesting_status, release_due_vesting_amount,
+        release_due_vesting_grant_amount, release_vesting_milestone, remove_shared_vesting_address,
+        ret_vesting_grant_status, ret_vesting_info, ret_vesting_status, revoke_vesting_grant,
+        set_vesting_address, set_vesting_pause, set_vesting_record, validate_vesting_configs,
+        VestingAddress, VestingConfig, VestingGrant,
     },
 };
+use strum::IntoEnumIterator;
+use time::Duration;
 
 #[no_mangle]
 pub extern "C" fn vesting_status() {
+    // Grants are queried by beneficiary
+    if let Some(beneficiary) = get_optional_named_arg_with_user_errors::<Option<Key>>(
+        ARG_BENEFICIARY,
+        VestingError::InvalidBeneficiary,
+    )
+    .flatten()
+    {
+        ret_vesting_grant_status(&beneficiary);
+    }
+
     let vesting_type: VestingType = get_named_arg_with_user_errors::<String>(
         ARG_VESTING_TYPE,
         VestingError::MissingVestingType,
@@ -87,13 +118,27 @@ pub extern "C" fn vesting_info() {
 // Check that some values are sent by token contract and return a TransferFilterContractResult
 #[no_mangle]
 pub extern "C" fn check_vesting_transfer() {
+    // Released amounts are recorded here, so only the token contract may call this entry point,
+    // other callers use preview_vesting_transfer
+    let (_, caller_package) = get_verified_caller();
+    if caller_package != Some(get_cowl_cep18_contract_package_hash().into()) {
+        revert(VestingError::InvalidTransferFilterCaller);
+    }
+
     let operator: Key = get_named_arg(ARG_OPERATOR);
     let from: Key = get_named_arg(ARG_FROM);
     let to: Key = get_named_arg(ARG_TO);
     let amount: U256 = get_named_arg(ARG_AMOUNT);
     let data: Option<Bytes> = get_named_arg(ARG_DATA);
 
-    let vesting_transfer = get_vesting_transfer(from, amount);
+    // The vesting package is the operator only of the moves its own entry points make, which
+    // are checked and accounted for in their records before the call: the initial allocations
+    // of set_allocations, the grant funding of create_grant, the balance moved between the
+    // addresses of a vesting type by accept_vesting_address and, in custody mode, the releases
+    // of claim and release_due and the revoked remainder of revoke. The token contract passes
+    // its own caller as operator, so no other caller can claim this exemption
+    let vesting_transfer = get_key(ARG_PACKAGE_HASH) == Some(operator)
+        || get_vesting_transfer(from, to, amount, false);
 
     record_event_dictionary(Event::CheckTransfer(CheckTransfer {
         operator,
@@ -110,6 +155,20 @@ pub extern "C" fn check_vesting_transfer() {
     ret(CLValue::from_t(TransferFilterContractResult::DenyTransfer).unwrap_or_revert());
 }
 
+// Returns the TransferFilterContractResult of a transfer without recording it
+#[no_mangle]
+pub extern "C" fn preview_vesting_transfer() {
+    let from: Key = get_named_arg(ARG_FROM);
+    let to: Key = get_named_arg(ARG_TO);
+    let amount: U256 = get_named_arg(ARG_AMOUNT);
+
+    if get_vesting_transfer(from, to, amount, true) {
+        ret(CLValue::from_t(TransferFilterContractResult::ProceedTransfer).unwrap_or_revert());
+    }
+
+    ret(CLValue::from_t(TransferFilterContractResult::DenyTransfer).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn set_cowl_cep18_contract_package() {
     sec_check(vec![SecurityBadge::Admin]);
@@ -170,29 +229,636 @@ pub extern "C" fn set_modalities() {
         }
     }
 
+    if let Some(treasury_address) = get_optional_named_arg_with_user_errors::<Option<Key>>(
+        ARG_TREASURY_ADDRESS,
+        VestingError::InvalidTreasuryAddress,
+    )
+    .flatten()
+    {
+        put_key(
+            ARG_TREASURY_ADDRESS,
+            storage::new_uref(treasury_address).into(),
+        );
+    }
+
     record_event_dictionary(Event::SetModalities(SetModalities {}));
 }
 
-/// Beware: do not remove the last Admin because that will lock out all admin functionality.
+/// Moves the start time of a vesting type that has not started yet to a later date
+#[no_mangle]
+pub extern "C" fn set_start_time() {
+    sec_check(vec![SecurityBadge::Admin, SecurityBadge::ScheduleManager]);
+
+    let vesting_type: VestingType = get_named_arg_with_user_errors::<String>(
+        ARG_VESTING_TYPE,
+        VestingError::MissingVestingType,
+        VestingError::InvalidVestingType,
+    )
+    .unwrap_or_revert()
+    .as_str()
+    .try_into()
+    .unwrap_or_revert_with(VestingError::InvalidVestingType);
+
+    let start_time: u64 = get_named_arg_with_user_errors(
+        ARG_START_TIME,
+        VestingError::MissingStartTime,
+        VestingError::InvalidStartTime,
+    )
+    .unwrap_or_revert();
+
+    let current_time_in_ms: u64 = get_blocktime().into();
+    let current_time = current_time_in_ms.checked_div(1000).unwrap_or_default();
+
+    let mut vesting_record = get_vesting_record(vesting_type);
+    let previous_start_time = vesting_record.start_time;
+
+    if previous_start_time <= current_time {
+        revert(VestingError::VestingAlreadyStarted);
+    }
+
+    if start_time <= current_time {
+        revert(VestingError::InvalidStartTime);
+    }
+
+    vesting_record.start_time = start_time;
+    refresh_vesting_status(&mut vesting_record);
+    set_vesting_record(&mut vesting_record);
+
+    let (caller, _) = get_verified_caller();
+
+    record_event_dictionary(Event::StartTimeUpdate(StartTimeUpdate {
+        key: caller,
+        vesting_type: vesting_type.to_string(),
+        previous_start_time,
+        start_time,
+    }));
+}
+
+/// Attests a milestone of a milestone schedule has been reached, vesting its share
+#[no_mangle]
+pub extern "C" fn release_milestone() {
+    sec_check(vec![SecurityBadge::Admin]);
+
+    let vesting_type: VestingType = get_named_arg_with_user_errors::<String>(
+        ARG_VESTING_TYPE,
+        VestingError::MissingVestingType,
+        VestingError::InvalidVestingType,
+    )
+    .unwrap_or_revert()
+    .as_str()
+    .try_into()
+    .unwrap_or_revert_with(VestingError::InvalidVestingType);
+
+    let milestone_name: String = get_named_arg_with_user_errors(
+        ARG_MILESTONE_NAME,
+        VestingError::MissingMilestoneName,
+        VestingError::InvalidMilestoneName,
+    )
+    .unwrap_or_revert();
+
+    let share = release_vesting_milestone(vesting_type, &milestone_name);
+
+    let (caller, _) = get_verified_caller();
+
+    record_event_dictionary(Event::MilestoneRelease(MilestoneRelease {
+        attester: caller,
+        vesting_type: vesting_type.to_string(),
+        milestone_name,
+        share: u32::from(share),
+    }));
+}
+
+/// Registers a grant for a single beneficiary, funded out of the allocation of a vesting type.
+/// In transfer mode the funding vesting address must have approved the vesting contract package
+/// for the grant amount.
+#[no_mangle]
+pub extern "C" fn create_grant() {
+    sec_check(vec![SecurityBadge::Admin]);
+
+    let vesting_grant: VestingGrant = get_named_arg_with_user_errors(
+        ARG_VESTING_GRANT,
+        VestingError::MissingVestingGrant,
+        VestingError::InvalidVestingGrant,
+    )
+    .unwrap_or_revert();
+
+    let vesting_grant = create_vesting_grant(vesting_grant);
+
+    let (caller, _) = get_verified_caller();
+
+    record_event_dictionary(Event::GrantCreation(GrantCreation {
+        key: caller,
+        beneficiary: vesting_grant.beneficiary,
+        funding_type: vesting_grant.funding_type.to_string(),
+        amount: vesting_grant.amount,
+        start_time: vesting_grant.start_time.unwrap_or_default(),
+    }));
+}
+
+/// Carves a grant with its own start time and cliff out of the Contributor allocation, funded
+/// like `create_grant`
+#[no_mangle]
+pub extern "C" fn create_contributor_grant() {
+    sec_check(vec![SecurityBadge::Admin]);
+
+    let beneficiary: Key = get_named_arg_with_user_errors(
+        ARG_BENEFICIARY,
+        VestingError::MissingBeneficiary,
+        VestingError::InvalidBeneficiary,
+    )
+    .unwrap_or_revert();
+
+    let amount: U256 = get_named_arg_with_user_errors(
+        ARG_AMOUNT,
+        VestingError::MissingAmount,
+        VestingError::InvalidAmount,
+    )
+    .unwrap_or_revert();
+
+    let start_time: Option<u64> = get_optional_named_arg_with_user_errors::<Option<u64>>(
+        ARG_START_TIME,
+        VestingError::InvalidStartTime,
+    )
+    .flatten();
+
+    let cliff_duration: Option<u64> = get_optional_named_arg_with_user_errors::<Option<u64>>(
+        ARG_CLIFF_DURATION,
+        VestingError::InvalidCliffDuration,
+    )
+    .flatten();
+
+    let is_revocable: bool = get_optional_named_arg_with_user_errors::<Option<bool>>(
+        ARG_REVOCABLE,
+        VestingError::InvalidVestingGrant,
+    )
+    .flatten()
+    .unwrap_or_default();
+
+    let vesting_grant = create_contributor_vesting_grant(
+        beneficiary,
+        amount,
+        start_time,
+        cliff_duration.map(|seconds| Duration::seconds(seconds as i64)),
+        is_revocable,
+    );
+
+    let (caller, _) = get_verified_caller();
+
+    record_event_dictionary(Event::GrantCreation(GrantCreation {
+        key: caller,
+        beneficiary: vesting_grant.beneficiary,
+        funding_type: vesting_grant.funding_type.to_string(),
+        amount: vesting_grant.amount,
+        start_time: vesting_grant.start_time.unwrap_or_default(),
+    }));
+}
+
+/// Revokes a revocable grant, its vesting stops at the vested amount and the unvested remainder
+/// held in custody is sent to the treasury address
+#[no_mangle]
+pub extern "C" fn revoke() {
+    sec_check(vec![SecurityBadge::Admin]);
+
+    let beneficiary: Key = get_named_arg_with_user_errors(
+        ARG_BENEFICIARY,
+        VestingError::MissingBeneficiary,
+        VestingError::InvalidBeneficiary,
+    )
+    .unwrap_or_revert();
+
+    let (vesting_grant, revoked_amount) = revoke_vesting_grant(&beneficiary);
+
+    let treasury_address =
+        get_treasury_address().unwrap_or_revert_with(VestingError::MissingTreasuryAddress);
+
+    if revoked_amount > U256::zero() {
+        call_versioned_contract::<()>(
+            get_cowl_cep18_contract_package_hash(),
+            None,
+            ENTRY_POINT_TRANSFER,
+            runtime_args! {
+                ARG_RECIPIENT => treasury_address,
+                ARG_AMOUNT => revoked_amount
+            },
+        );
+    }
+
+    let (caller, _) = get_verified_caller();
+
+    record_event_dictionary(Event::GrantRevocation(GrantRevocation {
+        key: caller,
+        beneficiary,
+        vested_amount: vesting_grant.amount,
+        revoked_amount,
+        treasury_address,
+        revoke_time: vesting_grant.revoke_time.unwrap_or_default(),
+    }));
+}
+
+/// Proposes a new address for a vesting type, the change is effective once the new address
+/// accepts it
+#[no_mangle]
+pub extern "C" fn change_vesting_address() {
+    sec_check(vec![SecurityBadge::Admin]);
+
+    let vesting_type: VestingType = get_named_arg_with_user_errors::<String>(
+        ARG_VESTING_TYPE,
+        VestingError::MissingVestingType,
+        VestingError::InvalidVestingType,
+    )
+    .unwrap_or_revert()
+    .as_str()
+    .try_into()
+    .unwrap_or_revert_with(VestingError::InvalidVestingType);
+
+    let vesting_address_key: Key = get_named_arg_with_user_errors(
+        ARG_VESTING_ADDRESS,
+        VestingError::MissingVestingAddress,
+        VestingError::InvalidVestingAddress,
+    )
+    .unwrap_or_revert();
+
+    if is_vesting_address(&vesting_address_key) {
+        revert(VestingError::InvalidVestingAddress);
+    }
+
+    set_dictionary_value_for_key(
+        DICT_PENDING_VESTING_ADDRESSES,
+        &vesting_type.to_string(),
+        &Some(vesting_address_key),
+    );
+
+    let (caller, _) = get_verified_caller();
+
+    record_event_dictionary(Event::VestingAddressProposal(VestingAddressProposal {
+        key: caller,
+        vesting_type: vesting_type.to_string(),
+        vesting_address: vesting_address_key,
+    }));
+}
+
+/// Accepts a pending address change: the schedule and the transferred amount of the vesting type
+/// move to the caller. In transfer mode the balance of the current address moves to the caller
+/// as well, up to the amount the current address approved the vesting contract package for. Its
+/// key may be lost, so the change does not depend on that approval: a balance left behind can
+/// only be sent to the addresses of the vesting type. Custody mode keeps allocations with the
+/// contract.
+#[no_mangle]
+pub extern "C" fn accept_vesting_address() {
+    let vesting_type: VestingType = get_named_arg_with_user_errors::<String>(
+        ARG_VESTING_TYPE,
+        VestingError::MissingVestingType,
+        VestingError::InvalidVestingType,
+    )
+    .unwrap_or_revert()
+    .as_str()
+    .try_into()
+    .unwrap_or_revert_with(VestingError::InvalidVestingType);
+
+    let (caller, _) = get_verified_caller();
+
+    let pending_vesting_address_key: Key = get_dictionary_value_from_key::<Option<Key>>(
+        DICT_PENDING_VESTING_ADDRESSES,
+        &vesting_type.to_string(),
+    )
+    .flatten()
+    .unwrap_or_revert_with(VestingError::MissingPendingVestingAddress);
+
+    if pending_vesting_address_key != caller {
+        revert(VestingError::InsufficientRights);
+    }
+
+    // The caller may have been given a schedule since the change was proposed
+    if is_vesting_address(&caller) {
+        revert(VestingError::InvalidVestingAddress);
+    }
+
+    let mut vesting_record = get_vesting_record(vesting_type);
+    let previous_vesting_address_key: Key = vesting_record
+        .vesting_info
+        .maybe_vesting_address_key
+        .unwrap_or_revert_with(VestingError::InvalidVestingType);
+
+    // The allocation address comes first among the addresses sharing the schedule
+    vesting_record.vesting_info.maybe_vesting_address_key = Some(caller);
+    vesting_record
+        .vesting_info
+        .vesting_address_keys
+        .retain(|key| *key != previous_vesting_address_key);
+    vesting_record
+        .vesting_info
+        .vesting_address_keys
+        .insert(0, caller);
+    set_vesting_record(&mut vesting_record);
+
+    let (moved_amount, previous_vesting_address) = match get_vesting_mode() {
+        VestingMode::Custody => (U256::zero(), None),
+        VestingMode::Transfer => {
+            let (moved_amount, remaining_amount) =
+                move_approved_balance(&previous_vesting_address_key, &caller);
+            let previous_vesting_address = if remaining_amount.is_zero() {
+                None
+            } else {
+                Some(VestingAddress::Previous(vesting_type))
+            };
+            (moved_amount, previous_vesting_address)
+        }
+    };
+    set_vesting_address(&previous_vesting_address_key, previous_vesting_address);
+    set_vesting_address(&caller, Some(VestingAddress::Type(vesting_type)));
+    set_dictionary_value_for_key(
+        DICT_PENDING_VESTING_ADDRESSES,
+        &vesting_type.to_string(),
+        &Option::<Key>::None,
+    );
+
+    record_event_dictionary(Event::VestingAddressChange(VestingAddressChange {
+        vesting_type: vesting_type.to_string(),
+        previous_address: previous_vesting_address_key,
+        new_address: caller,
+        moved_amount,
+    }));
+}
+
+/// Adds an address sharing the schedule of a vesting type, as long as it holds no tokens
+#[no_mangle]
+pub extern "C" fn add_vesting_address() {
+    sec_check(vec![SecurityBadge::Admin]);
+
+    let vesting_type: VestingType = get_named_arg_with_user_errors::<String>(
+        ARG_VESTING_TYPE,
+        VestingError::MissingVestingType,
+        VestingError::InvalidVestingType,
+    )
+    .unwrap_or_revert()
+    .as_str()
+    .try_into()
+    .unwrap_or_revert_with(VestingError::InvalidVestingType);
+
+    let vesting_address_key: Key = get_named_arg_with_user_errors(
+        ARG_VESTING_ADDRESS,
+        VestingError::MissingVestingAddress,
+        VestingError::InvalidVestingAddress,
+    )
+    .unwrap_or_revert();
+
+    add_shared_vesting_address(vesting_type, vesting_address_key);
+
+    let (caller, _) = get_verified_caller();
+
+    record_event_dictionary(Event::VestingAddressAddition(VestingAddressAddition {
+        key: caller,
+        vesting_type: vesting_type.to_string(),
+        vesting_address: vesting_address_key,
+    }));
+}
+
+/// Removes an address sharing the schedule of a vesting type, once its balance has been moved
+#[no_mangle]
+pub extern "C" fn remove_vesting_address() {
+    sec_check(vec![SecurityBadge::Admin]);
+
+    let vesting_type: VestingType = get_named_arg_with_user_errors::<String>(
+        ARG_VESTING_TYPE,
+        VestingError::MissingVestingType,
+        VestingError::InvalidVestingType,
+    )
+    .unwrap_or_revert()
+    .as_str()
+    .try_into()
+    .unwrap_or_revert_with(VestingError::InvalidVestingType);
+
+    let vesting_address_key: Key = get_named_arg_with_user_errors(
+        ARG_VESTING_ADDRESS,
+        VestingError::MissingVestingAddress,
+        VestingError::InvalidVestingAddress,
+    )
+    .unwrap_or_revert();
+
+    remove_shared_vesting_address(vesting_type, vesting_address_key);
+
+    let (caller, _) = get_verified_caller();
+
+    record_event_dictionary(Event::VestingAddressRemoval(VestingAddressRemoval {
+        key: caller,
+        vesting_type: vesting_type.to_string(),
+        vesting_address: vesting_address_key,
+    }));
+}
+
+/// Releases the available amount held in custody to the calling vesting address or beneficiary
+#[no_mangle]
+pub extern "C" fn claim() {
+    if get_vesting_mode() != VestingMode::Custody {
+        revert(VestingError::InvalidVestingMode);
+    }
+
+    let (caller, _) = get_verified_caller();
+
+    let (vesting_type, amount) = claim_vesting_amount(&caller);
+
+    transfer_released_amount(caller, vesting_type, amount);
+}
+
+/// Pushes the amount due to each vesting type, or to the given batch, to its vesting address,
+/// then the amount due to each grant of the given beneficiaries, contributor grants included.
+/// Grants are not enumerable and are only pushed when listed. Admins and keepers can trigger it,
+/// the amounts only go to the vesting addresses and beneficiaries. Custody mode only, vesting
+/// addresses and beneficiaries already hold their tokens in transfer mode. Paused schedules are
+/// left for a later run and reported with a `VestingReleaseSkip` event.
+#[no_mangle]
+pub extern "C" fn release_due() {
+    sec_check(vec![SecurityBadge::Admin, SecurityBadge::Keeper]);
+
+    if get_vesting_mode() != VestingMode::Custody {
+        revert(VestingError::InvalidVestingMode);
+    }
+
+    let vesting_types: Vec<VestingType> =
+        match get_optional_named_arg_with_user_errors::<Option<Vec<String>>>(
+            ARG_VESTING_TYPES,
+            VestingError::InvalidVestingType,
+        )
+        .flatten()
+        {
+            Some(vesting_types) => vesting_types
+                .iter()
+                .map(|vesting_type| {
+                    vesting_type
+                        .as_str()
+                        .try_into()
+                        .unwrap_or_revert_with(VestingError::InvalidVestingType)
+                })
+                .collect(),
+            None => VestingType::iter().collect(),
+        };
+
+    let beneficiaries: Vec<Key> = get_optional_named_arg_with_user_errors::<Option<Vec<Key>>>(
+        ARG_BENEFICIARIES,
+        VestingError::InvalidBeneficiary,
+    )
+    .flatten()
+    .unwrap_or_default();
+
+    let global_vesting_state = get_global_vesting_state();
+
+    for vesting_type in vesting_types {
+        let (recipient, maybe_amount) =
+            release_due_vesting_amount(vesting_type, &global_vesting_state);
+        push_due_amount(recipient, vesting_type, maybe_amount);
+    }
+
+    for beneficiary in beneficiaries {
+        let (funding_type, maybe_amount) =
+            release_due_vesting_grant_amount(&beneficiary, &global_vesting_state);
+        push_due_amount(beneficiary, funding_type, maybe_amount);
+    }
+}
+
+fn push_due_amount(recipient: Key, vesting_type: VestingType, maybe_amount: Option<U256>) {
+    match maybe_amount {
+        // Paused schedules are left for a later run
+        None => record_event_dictionary(Event::VestingReleaseSkip(VestingReleaseSkip {
+            recipient,
+            vesting_type: vesting_type.to_string(),
+        })),
+        Some(amount) if amount.is_zero() => {}
+        Some(amount) => transfer_released_amount(recipient, vesting_type, amount),
+    }
+}
+
+/// Pauses the outflows of a vesting type, or of all vesting types if none is given
+#[no_mangle]
+pub extern "C" fn pause() {
+    sec_check(vec![SecurityBadge::Admin, SecurityBadge::Pauser]);
+
+    let vesting_type = get_optional_vesting_type();
+    set_vesting_pause(vesting_type, true);
+
+    let (caller, _) = get_verified_caller();
+
+    record_event_dictionary(Event::VestingPause(VestingPause {
+        key: caller,
+        vesting_type: vesting_type.map(|vesting_type| vesting_type.to_string()),
+    }));
+}
+
+/// Resumes the outflows of a vesting type, or lifts the pause of all vesting types if none is
+/// given. Vesting types paused on their own stay paused.
+#[no_mangle]
+pub extern "C" fn unpause() {
+    sec_check(vec![SecurityBadge::Admin, SecurityBadge::Pauser]);
+
+    let vesting_type = get_optional_vesting_type();
+    set_vesting_pause(vesting_type, false);
+
+    let (caller, _) = get_verified_caller();
+
+    record_event_dictionary(Event::VestingUnpause(VestingUnpause {
+        key: caller,
+        vesting_type: vesting_type.map(|vesting_type| vesting_type.to_string()),
+    }));
+}
+
+/// Makes a vesting type, or all vesting types if none is given, fully vested from the
+/// acceleration time, the current block time if not set. Schedules are kept as they were.
+#[no_mangle]
+pub extern "C" fn accelerate() {
+    sec_check(vec![SecurityBadge::Admin]);
+
+    let vesting_type = get_optional_vesting_type();
+
+    let current_time_in_ms: u64 = get_blocktime().into();
+    let acceleration_time: u64 = get_optional_named_arg_with_user_errors::<Option<u64>>(
+        ARG_ACCELERATION_TIME,
+        VestingError::InvalidAccelerationTime,
+    )
+    .flatten()
+    .unwrap_or(current_time_in_ms.checked_div(1000).unwrap_or_default());
+
+    accelerate_vesting(vesting_type, acceleration_time);
+
+    let (caller, _) = get_verified_caller();
+
+    record_event_dictionary(Event::VestingAcceleration(VestingAcceleration {
+        key: caller,
+        vesting_type: vesting_type.map(|vesting_type| vesting_type.to_string()),
+        acceleration_time,
+    }));
+}
+
+fn get_optional_vesting_type() -> Option<VestingType> {
+    get_optional_named_arg_with_user_errors::<Option<String>>(
+        ARG_VESTING_TYPE,
+        VestingError::InvalidVestingType,
+    )
+    .flatten()
+    .map(|vesting_type| {
+        vesting_type
+            .as_str()
+            .try_into()
+            .unwrap_or_revert_with(VestingError::InvalidVestingType)
+    })
+}
+
+fn transfer_released_amount(recipient: Key, vesting_type: VestingType, amount: U256) {
+    call_versioned_contract::<()>(
+        get_cowl_cep18_contract_package_hash(),
+        None,
+        ENTRY_POINT_TRANSFER,
+        runtime_args! {
+            ARG_RECIPIENT => recipient,
+            ARG_AMOUNT => amount
+        },
+    );
+
+    record_event_dictionary(Event::VestingRelease(VestingRelease {
+        recipient,
+        vesting_type: vesting_type.to_string(),
+        amount,
+    }));
+}
+
+/// Changes that would leave no Admin revert, removing the last one would lock out all admin
+/// functionality. Admins are not granted here, they take the badge by accepting `propose_admin`.
 #[no_mangle]
 pub extern "C" fn change_security() {
     sec_check(vec![SecurityBadge::Admin]);
 
     let admin_list: Option<Vec<Key>> =
         get_optional_named_arg_with_user_errors(ADMIN_LIST, VestingError::InvalidAdminList);
-    let none_list: Option<Vec<Key>> =
-        get_optional_named_arg_with_user_errors(NONE_LIST, VestingError::InvalidNoneList);
+    if !admin_list.unwrap_or_default().is_empty() {
+        revert(VestingError::AdminGrantRequiresProposal);
+    }
 
     let mut badge_map: BTreeMap<Key, SecurityBadge> = BTreeMap::new();
 
-    if let Some(admin_list) = admin_list {
-        for account_key in admin_list {
-            badge_map.insert(account_key, SecurityBadge::Admin);
-        }
-    }
-    if let Some(none_list) = none_list {
-        for account_key in none_list {
-            badge_map.insert(account_key, SecurityBadge::None);
+    // A key listed several times gets the badge of the last list, none list last
+    for (list_name, badge, error) in [
+        (
+            PAUSER_LIST,
+            SecurityBadge::Pauser,
+            VestingError::InvalidPauserList,
+        ),
+        (
+            SCHEDULE_MANAGER_LIST,
+            SecurityBadge::ScheduleManager,
+            VestingError::InvalidScheduleManagerList,
+        ),
+        (
+            KEEPER_LIST,
+            SecurityBadge::Keeper,
+            VestingError::InvalidKeeperList,
+        ),
+        (
+            NONE_LIST,
+            SecurityBadge::None,
+            VestingError::InvalidNoneList,
+        ),
+    ] {
+        let list: Option<Vec<Key>> = get_optional_named_arg_with_user_errors(list_name, error);
+        for account_key in list.unwrap_or_default() {
+            badge_map.insert(account_key, badge);
         }
     }
 
@@ -206,6 +872,75 @@ pub extern "C" fn change_security() {
     }));
 }
 
+/// Nominates a key as admin, the badge is only granted once the nominee accepts it
+#[no_mangle]
+pub extern "C" fn propose_admin() {
+    sec_check(vec![SecurityBadge::Admin]);
+
+    let admin: Key = get_named_arg(ARG_ADMIN);
+
+    if get_security_badge(&admin) == Some(SecurityBadge::Admin) {
+        revert(VestingError::AlreadyAdmin);
+    }
+
+    let current_time_in_ms: u64 = get_blocktime().into();
+    let expiry_time = current_time_in_ms
+        .checked_div(1000)
+        .unwrap_or_default()
+        .checked_add(ADMIN_PROPOSAL_DURATION_IN_SECONDS)
+        .unwrap_or_revert_with(VestingError::Overflow);
+
+    set_admin_proposal(&admin, Some(expiry_time));
+
+    let (caller, _) = get_verified_caller();
+
+    record_event_dictionary(Event::AdminProposal(AdminProposal {
+        key: caller,
+        admin,
+        expiry_time,
+    }));
+}
+
+#[no_mangle]
+pub extern "C" fn accept_admin() {
+    let (caller, _) = get_verified_caller();
+
+    let expiry_time =
+        get_admin_proposal(&caller).unwrap_or_revert_with(VestingError::MissingAdminProposal);
+
+    let current_time_in_ms: u64 = get_blocktime().into();
+    if current_time_in_ms.checked_div(1000).unwrap_or_default() > expiry_time {
+        revert(VestingError::AdminProposalExpired);
+    }
+
+    set_admin_proposal(&caller, None);
+
+    let mut badge_map: BTreeMap<Key, SecurityBadge> = BTreeMap::new();
+    badge_map.insert(caller, SecurityBadge::Admin);
+    change_sec_badge(&badge_map);
+
+    record_event_dictionary(Event::AdminAcceptance(AdminAcceptance { admin: caller }));
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_admin_proposal() {
+    sec_check(vec![SecurityBadge::Admin]);
+
+    let admin: Key = get_named_arg(ARG_ADMIN);
+
+    if get_admin_proposal(&admin).is_none() {
+        revert(VestingError::MissingAdminProposal);
+    }
+
+    set_admin_proposal(&admin, None);
+
+    let (caller, _) = get_verified_caller();
+
+    record_event_dictionary(Event::AdminProposalCancellation(
+        AdminProposalCancellation { key: caller, admin },
+    ));
+}
+
 #[no_mangle]
 pub extern "C" fn install() {
     if get_key(ARG_PACKAGE_HASH).is_some() {
@@ -233,6 +968,8 @@ pub extern "C" fn install() {
     init_events();
 
     storage::new_dictionary(DICT_SECURITY_BADGES).unwrap_or_revert();
+    storage::new_dictionary(DICT_ADMIN_PROPOSALS).unwrap_or_revert();
+    put_key(ARG_ADMIN_COUNT, storage::new_uref(0u32).into());
 
     let mut badge_map: BTreeMap<Key, SecurityBadge> = BTreeMap::new();
 
@@ -259,10 +996,7 @@ pub extern "C" fn install() {
         }
     }
 
-    set_allocations(
-        &vesting_contract_hash_key,
-        &vesting_contract_package_hash_key,
-    );
+    set_allocations(&vesting_contract_package_hash_key);
 
     change_sec_badge(&badge_map);
 }
@@ -282,6 +1016,49 @@ pub extern "C" fn upgrade() {
         .unwrap_or_revert(),
     );
 
+    // Contracts installed before the admin count was kept count the upgrading admin and the
+    // admins listed at upgrade
+    if get_key(ARG_ADMIN_COUNT).is_none() {
+        let mut admin_keys: Vec<Key> =
+            get_optional_named_arg_with_user_errors(ADMIN_LIST, VestingError::InvalidAdminList)
+                .unwrap_or_default();
+        admin_keys.push(get_verified_caller().0);
+        init_admin_count(&admin_keys);
+    }
+
+    // Dictionaries introduced after the first release are created if missing
+    for dictionary_name in [
+        DICT_VESTING_GRANTS,
+        DICT_PENDING_VESTING_ADDRESSES,
+        DICT_ADMIN_PROPOSALS,
+    ] {
+        if get_key(dictionary_name).is_none() {
+            storage::new_dictionary(dictionary_name).unwrap_or_revert();
+        }
+    }
+
+    // The per-type dictionaries of the first release are folded into one record per type
+    if get_key(DICT_VESTING_RECORDS).is_none() {
+        storage::new_dictionary(DICT_VESTING_RECORDS).unwrap_or_revert();
+        migrate_vesting_records();
+    }
+
+    // The address lookup is built from the vesting addresses already set
+    if get_key(DICT_VESTING_ADDRESSES).is_none() {
+        storage::new_dictionary(DICT_VESTING_ADDRESSES).unwrap_or_revert();
+        for vesting_info in get_vesting_info() {
+            for vesting_address_key in vesting_info.vesting_address_keys.iter() {
+                set_vesting_address(
+                    vesting_address_key,
+                    Some(VestingAddress::Type(vesting_info.vesting_type)),
+                );
+            }
+        }
+    }
+
+    // The schemas stored at install lack the events added since
+    init_events();
+
     record_event_dictionary(Event::Upgrade(Upgrade {}))
 }
 
@@ -290,6 +1067,20 @@ fn install_contract(name: &str) {
         get_optional_named_arg_with_user_errors(ARG_EVENTS_MODE, VestingError::InvalidEventsMode)
             .unwrap_or_default();
 
+    let vesting_mode: VestingMode = get_optional_named_arg_with_user_errors::<u8>(
+        ARG_VESTING_MODE,
+        VestingError::InvalidVestingMode,
+    )
+    .unwrap_or_default()
+    .try_into()
+    .unwrap_or_revert();
+
+    let treasury_address: Option<Key> = get_optional_named_arg_with_user_errors::<Option<Key>>(
+        ARG_TREASURY_ADDRESS,
+        VestingError::InvalidTreasuryAddress,
+    )
+    .flatten();
+
     let cowl_cep18_contract_package_key: Key = get_named_arg(ARG_COWL_CEP18_CONTRACT_PACKAGE);
 
     let cowl_cep18_contract_package_hash = ContractPackageHash::from(
@@ -304,6 +1095,10 @@ fn install_contract(name: &str) {
             ARG_EVENTS_MODE.to_string(),
             storage::new_uref(events_mode).into(),
         ),
+        (
+            ARG_VESTING_MODE.to_string(),
+            storage::new_uref(vesting_mode as u8).into(),
+        ),
         (ARG_INSTALLER.to_string(), get_caller().into()),
         (
             ARG_COWL_CEP18_CONTRACT_PACKAGE.to_string(),
@@ -316,6 +1111,13 @@ fn install_contract(name: &str) {
         named_keys.insert(key, value);
     }
 
+    if let Some(treasury_address) = treasury_address {
+        named_keys.insert(
+            ARG_TREASURY_ADDRESS.to_string(),
+            storage::new_uref(treasury_address).into(),
+        );
+    }
+
     let entry_points = generate_entry_points();
 
     let package_key_name = format!("{PREFIX_CONTRACT_PACKAGE_NAME}_{name}");
@@ -368,6 +1170,17 @@ fn install_contract(name: &str) {
         init_args.insert(NONE_LIST, none_list).unwrap_or_revert();
     }
 
+    let vesting_configs: Option<Vec<VestingConfig>> = get_optional_named_arg_with_user_errors(
+        ARG_VESTING_ALLOCATIONS,
+        VestingError::InvalidVestingAllocations,
+    );
+
+    if let Some(vesting_configs) = vesting_configs {
+        init_args
+            .insert(ARG_VESTING_ALLOCATIONS, vesting_configs)
+            .unwrap_or_revert();
+    }
+
     // Add vesting package to minter list
     call_versioned_contract::<()>(
         cowl_cep18_contract_package_hash,
@@ -381,16 +1194,19 @@ fn install_contract(name: &str) {
     // Proceed to allocations to vesting addresses
     call_contract::<()>(contract_hash, ENTRY_POINT_INSTALL, init_args);
 
-    // Check transfer filter package and method
-    call_versioned_contract::<()>(
-        cowl_cep18_contract_package_hash,
-        None,
-        ENTRY_POINT_SET_TRANSFER_FILTER,
-        runtime_args! {
-            ARG_TRANSFER_FILTER_CONTRACT_PACKAGE => Some(package_hash_key),
-            ARG_TRANSFER_FILTER_METHOD => Some(ENTRY_POINT_CHECK_VESTING_TRANSFER),
-        },
-    );
+    // Locked tokens never leave the contract in custody mode, transfers need no filter
+    if vesting_mode == VestingMode::Transfer {
+        // Check transfer filter package and method
+        call_versioned_contract::<()>(
+            cowl_cep18_contract_package_hash,
+            None,
+            ENTRY_POINT_SET_TRANSFER_FILTER,
+            runtime_args! {
+                ARG_TRANSFER_FILTER_CONTRACT_PACKAGE => Some(package_hash_key),
+                ARG_TRANSFER_FILTER_METHOD => Some(ENTRY_POINT_CHECK_VESTING_TRANSFER),
+            },
+        );
+    }
 
     // Remove vesting package from minter list and add it to none list
     call_versioned_contract::<()>(
@@ -434,27 +1250,64 @@ fn upgrade_contract(name: &str) {
 
     let contract_hash_key = Key::from(contract_hash);
 
-    let runtime_args = runtime_args! {
+    let mut runtime_args = runtime_args! {
         ARG_CONTRACT_HASH => contract_hash_key,
     };
 
+    let admin_list: Option<Vec<Key>> =
+        get_optional_named_arg_with_user_errors(ADMIN_LIST, VestingError::InvalidAdminList);
+
+    if let Some(admin_list) = admin_list {
+        runtime_args
+            .insert(ADMIN_LIST, admin_list)
+            .unwrap_or_revert();
+    }
+
     call_contract::<()>(contract_hash, ENTRY_POINT_UPGRADE, runtime_args);
 }
 
-pub fn set_allocations(vesting_contract_hash_key: &Key, vesting_contract_package_hash_key: &Key) {
-    storage::new_dictionary(DICT_ADDRESSES).unwrap_or_revert();
-    storage::new_dictionary(DICT_START_TIME).unwrap_or_revert();
-    storage::new_dictionary(DICT_VESTING_AMOUNT).unwrap_or_revert();
-    storage::new_dictionary(DICT_VESTING_INFO).unwrap_or_revert();
-    storage::new_dictionary(DICT_VESTING_STATUS).unwrap_or_revert();
-    storage::new_dictionary(DICT_TRANSFERRED_AMOUNT).unwrap_or_revert();
+pub fn set_allocations(vesting_contract_package_hash_key: &Key) {
+    storage::new_dictionary(DICT_VESTING_RECORDS).unwrap_or_revert();
+    storage::new_dictionary(DICT_VESTING_GRANTS).unwrap_or_revert();
+    storage::new_dictionary(DICT_PENDING_VESTING_ADDRESSES).unwrap_or_revert();
+    storage::new_dictionary(DICT_VESTING_ADDRESSES).unwrap_or_revert();
 
-    for vesting_info in VESTING_INFO.iter() {
-        set_dictionary_value_for_key(
-            DICT_ADDRESSES,
-            &vesting_info.vesting_type.to_string(),
-            &get_named_arg::<Key>(&vesting_info.vesting_type.to_string()),
+    // Allocation table from install args, compile-time defaults otherwise
+    let vesting_configs: Vec<VestingConfig> = get_optional_named_arg_with_user_errors(
+        ARG_VESTING_ALLOCATIONS,
+        VestingError::InvalidVestingAllocations,
+    )
+    .unwrap_or_else(get_default_vesting_configs);
+
+    validate_vesting_configs(&vesting_configs).unwrap_or_revert();
+
+    let install_time_in_ms: u64 = get_blocktime().into();
+    let install_time_in_seconds = install_time_in_ms.checked_div(1000).unwrap_or_default();
+
+    for vesting_config in vesting_configs.iter() {
+        let vesting_address_key = get_named_arg::<Key>(&vesting_config.vesting_type.to_string());
+
+        set_vesting_address(
+            &vesting_address_key,
+            Some(VestingAddress::Type(vesting_config.vesting_type)),
         );
+
+        // Vesting starts at install unless a later start time (TGE) is scheduled
+        let start_time_in_seconds = vesting_config.start_time.unwrap_or(install_time_in_seconds);
+
+        if start_time_in_seconds < install_time_in_seconds {
+            revert(VestingError::InvalidStartTime);
+        }
+
+        // The allocated amount is set once the initial balances are written
+        let mut vesting_record = new_vesting_record(
+            vesting_config.to_vesting_info(Some(vesting_address_key)),
+            start_time_in_seconds,
+            U256::zero(),
+            vesting_config.tranches.clone(),
+            vesting_config.milestones.clone(),
+        );
+        set_vesting_record(&mut vesting_record);
     }
 
     let cowl_cep18_contract_package_hash = get_cowl_cep18_contract_package_hash();
@@ -480,10 +1333,21 @@ pub fn set_allocations(vesting_contract_hash_key: &Key, vesting_contract_package
         },
     );
 
-    let allocations = calculate_vesting_allocations(total_supply);
+    let allocations = calculate_vesting_allocations(total_supply, &vesting_configs);
+
+    let vesting_mode = get_vesting_mode();
 
     // Write initial balances
     for allocation in allocations {
+        // Custody keeps every allocation in the package until claimed
+        if vesting_mode == VestingMode::Custody {
+            let mut vesting_record = get_vesting_record(allocation.vesting_type);
+            vesting_record.vesting_amount = allocation.vesting_amount;
+            refresh_vesting_status(&mut vesting_record);
+            set_vesting_record(&mut vesting_record);
+            continue;
+        }
+
         if allocation.vesting_amount > U256::zero() {
             call_versioned_contract::<()>(
                 cowl_cep18_contract_package_hash,
@@ -505,30 +1369,10 @@ pub fn set_allocations(vesting_contract_hash_key: &Key, vesting_contract_package
             revert(VestingError::InvalidRecepientAllocation);
         }
 
-        set_dictionary_value_for_key(
-            DICT_VESTING_AMOUNT,
-            &allocation.vesting_type.to_string(),
-            &recipient_balance,
-        );
-
-        let start_time_in_ms: u64 = get_blocktime().into();
-        let start_time_in_seconds = start_time_in_ms.checked_div(1000).unwrap_or_default();
-
-        set_dictionary_value_for_key(
-            DICT_START_TIME,
-            &allocation.vesting_type.to_string(),
-            &start_time_in_seconds,
-        );
-
-        let _ = update_vesting_status(allocation.vesting_type);
-    }
-
-    for vesting_info in get_vesting_info().iter() {
-        set_dictionary_value_for_key(
-            DICT_VESTING_INFO,
-            &vesting_info.vesting_type.to_string(),
-            &vesting_info,
-        );
+        let mut vesting_record = get_vesting_record(allocation.vesting_type);
+        vesting_record.vesting_amount = recipient_balance;
+        refresh_vesting_status(&mut vesting_record);
+        set_vesting_record(&mut vesting_record);
     }
 
     let actual_supply: U256 = call_versioned_contract(
@@ -542,11 +1386,19 @@ pub fn set_allocations(vesting_contract_hash_key: &Key, vesting_contract_package
         revert(VestingError::InvalidInstallerTotalSupply);
     }
 
-    let vesting_contract_balance: U256 =
-        get_current_balance_for_key(cowl_cep18_contract_package_hash, vesting_contract_hash_key);
+    // Minted supply is owned by the package, allocations include the rounding dust
+    let vesting_contract_balance: U256 = get_current_balance_for_key(
+        cowl_cep18_contract_package_hash,
+        vesting_contract_package_hash_key,
+    );
+
+    let expected_contract_balance = match vesting_mode {
+        VestingMode::Custody => total_supply,
+        VestingMode::Transfer => U256::zero(),
+    };
 
-    // //! Vesting contract should not have remaining funds after installation
-    if vesting_contract_balance != U256::zero() {
+    // //! Vesting contract should not have remaining funds after installation unless in custody
+    if vesting_contract_balance != expected_contract_balance {
         revert(VestingError::InvalidInstallerTotalSupply);
     }
 }
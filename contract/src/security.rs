@@ -1,8 +1,14 @@
 #[cfg(feature = "contract-support")]
-use alloc::collections::BTreeMap;
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::{vec, vec::Vec};
 #[cfg(feature = "contract-support")]
-use casper_contract::{contract_api::runtime::revert, unwrap_or_revert::UnwrapOrRevert};
+use casper_contract::{
+    contract_api::{
+        runtime::{put_key, revert},
+        storage,
+    },
+    unwrap_or_revert::UnwrapOrRevert,
+};
 #[cfg(feature = "contract-support")]
 use casper_types::Key;
 use casper_types::{
@@ -12,15 +18,28 @@ use casper_types::{
 
 #[cfg(feature = "contract-support")]
 use crate::{
-    constants::DICT_SECURITY_BADGES,
+    constants::{ARG_ADMIN_COUNT, DICT_ADMIN_PROPOSALS, DICT_SECURITY_BADGES},
     error::VestingError,
-    utils::{get_dictionary_value_from_key, get_verified_caller, set_dictionary_value_for_key},
+    utils::{
+        get_dictionary_value_from_key, get_stored_value_with_user_errors, get_verified_caller,
+        set_dictionary_value_for_key,
+    },
 };
 
+/// Each key holds one badge, entry points list the badges allowed to call them
+///
+/// There is no auditor badge: the views `vesting_status`, `vesting_info` and
+/// `preview_vesting_transfer` stay open to anyone, so auditors need no role to query them
 #[repr(u8)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum SecurityBadge {
     Admin = 0,
+    /// Pauses and resumes vesting outflows
+    Pauser = 1,
+    /// Sets the start time of vesting schedules
+    ScheduleManager = 2,
+    /// Pushes due releases to the vesting addresses
+    Keeper = 3,
     None = 99,
 }
 
@@ -45,6 +64,9 @@ impl FromBytes for SecurityBadge {
         Ok((
             match bytes[0] {
                 0 => SecurityBadge::Admin,
+                1 => SecurityBadge::Pauser,
+                2 => SecurityBadge::ScheduleManager,
+                3 => SecurityBadge::Keeper,
                 99 => SecurityBadge::None,
                 _ => return Err(bytesrepr::Error::LeftOverBytes),
             },
@@ -68,20 +90,82 @@ pub fn sec_check(allowed_badge_list: Vec<SecurityBadge>) {
 }
 
 #[cfg(feature = "contract-support")]
-fn get_security_badge(entity: &Key) -> Option<SecurityBadge> {
+pub fn get_security_badge(entity: &Key) -> Option<SecurityBadge> {
     get_dictionary_value_from_key(
         DICT_SECURITY_BADGES,
         &hex::encode(entity.to_bytes().unwrap_or_revert()),
     )
 }
 
+/// Applies the badge changes and keeps the admin count, reverts if no admin would be left
 #[cfg(feature = "contract-support")]
 pub fn change_sec_badge(badge_map: &BTreeMap<Key, SecurityBadge>) {
+    let mut admin_count = get_admin_count();
+
     for (&user, &badge) in badge_map {
+        let was_admin = get_security_badge(&user) == Some(SecurityBadge::Admin);
+        let is_admin = badge == SecurityBadge::Admin;
+
+        if is_admin && !was_admin {
+            admin_count = admin_count
+                .checked_add(1)
+                .unwrap_or_revert_with(VestingError::Overflow);
+        } else if was_admin && !is_admin {
+            admin_count = admin_count.saturating_sub(1);
+        }
+
         set_dictionary_value_for_key(
             DICT_SECURITY_BADGES,
             &hex::encode(user.to_bytes().unwrap_or_revert()),
             &badge,
         );
     }
+
+    if admin_count == 0 {
+        revert(VestingError::LastAdminRemoval);
+    }
+
+    put_key(ARG_ADMIN_COUNT, storage::new_uref(admin_count).into());
+}
+
+/// Expiry time in seconds of the pending admin handover to the key, if any
+#[cfg(feature = "contract-support")]
+pub fn get_admin_proposal(admin: &Key) -> Option<u64> {
+    get_dictionary_value_from_key::<Option<u64>>(
+        DICT_ADMIN_PROPOSALS,
+        &hex::encode(admin.to_bytes().unwrap_or_revert()),
+    )
+    .flatten()
+}
+
+#[cfg(feature = "contract-support")]
+pub fn set_admin_proposal(admin: &Key, expiry_time: Option<u64>) {
+    set_dictionary_value_for_key(
+        DICT_ADMIN_PROPOSALS,
+        &hex::encode(admin.to_bytes().unwrap_or_revert()),
+        &expiry_time,
+    );
+}
+
+#[cfg(feature = "contract-support")]
+pub fn get_admin_count() -> u32 {
+    get_stored_value_with_user_errors(
+        ARG_ADMIN_COUNT,
+        VestingError::MissingAdminCount,
+        VestingError::InvalidAdminCount,
+    )
+}
+
+/// Seeds the admin count of contracts installed before it was kept, from the given keys holding
+/// the admin badge
+#[cfg(feature = "contract-support")]
+pub fn init_admin_count(admin_keys: &[Key]) {
+    let admin_count = admin_keys
+        .iter()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter(|admin_key| get_security_badge(admin_key) == Some(SecurityBadge::Admin))
+        .count() as u32;
+
+    put_key(ARG_ADMIN_COUNT, storage::new_uref(admin_count).into());
 }
@@ -1,5 +1,6 @@
 #[cfg(feature = "contract-support")]
 use crate::constants::ARG_COWL_CEP18_CONTRACT_PACKAGE;
+use crate::constants::MONTH_IN_SECONDS;
 #[cfg(feature = "contract-support")]
 use crate::error::VestingError;
 #[cfg(feature = "contract-support")]
@@ -25,7 +26,7 @@ use casper_types::{
 };
 #[cfg(feature = "contract-support")]
 use core::{convert::TryInto, mem::MaybeUninit};
-use time::Duration;
+use time::{Date, Duration, Month, OffsetDateTime};
 
 #[cfg(feature = "contract-support")]
 pub enum Caller {
@@ -311,3 +312,58 @@ pub fn display_human_readable_duration(duration: Duration) -> String {
 
     format!("{}d {:02}h {:02}m {:02}s", days, hours, minutes, seconds)
 }
+
+/// Adds calendar months to a unix timestamp, keeping its day-of-month and time of day.
+/// The day is clamped to the last day of shorter months, without drifting on later months.
+pub fn add_calendar_months(timestamp: u64, months: u64) -> Option<u64> {
+    let date_time = OffsetDateTime::from_unix_timestamp(i64::try_from(timestamp).ok()?).ok()?;
+    let month_index = i64::from(date_time.year())
+        .checked_mul(12)?
+        .checked_add(i64::from(u8::from(date_time.month())) - 1)?
+        .checked_add(i64::try_from(months).ok()?)?;
+
+    let year = i32::try_from(month_index.div_euclid(12)).ok()?;
+    let month = Month::try_from(month_index.rem_euclid(12) as u8 + 1).ok()?;
+    let day = date_time.day().min(month.length(year));
+
+    let date = Date::from_calendar_date(year, month, day).ok()?;
+    u64::try_from(
+        date.with_time(date_time.time())
+            .assume_utc()
+            .unix_timestamp(),
+    )
+    .ok()
+}
+
+/// Number of whole calendar months elapsed between two unix timestamps
+pub fn elapsed_calendar_months(start_time: u64, current_time: u64) -> u64 {
+    if current_time <= start_time {
+        return 0;
+    }
+
+    let (Ok(start), Ok(current)) = (
+        OffsetDateTime::from_unix_timestamp(start_time as i64),
+        OffsetDateTime::from_unix_timestamp(current_time as i64),
+    ) else {
+        return 0;
+    };
+
+    let months = i64::from(current.year() - start.year()) * 12
+        + i64::from(u8::from(current.month()))
+        - i64::from(u8::from(start.month()));
+    let mut months = months.max(0) as u64;
+
+    // The month difference overshoots by one when the release day of this month is not reached
+    while months > 0
+        && add_calendar_months(start_time, months).is_none_or(|release| release > current_time)
+    {
+        months -= 1;
+    }
+    months
+}
+
+/// Number of calendar months spanned by a release period, rounded to the nearest month
+pub fn calendar_months_in_period(vesting_period: Duration) -> u64 {
+    let vesting_period = vesting_period.whole_seconds().max(0) as u64;
+    (vesting_period.saturating_add(MONTH_IN_SECONDS / 2) / MONTH_IN_SECONDS).max(1)
+}
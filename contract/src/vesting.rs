@@ -1,23 +1,34 @@
-use crate::{
-    constants::VESTING_PERIOD_IN_SECONDS, enums::VestingType,
-    utils::display_human_readable_duration,
-};
 #[cfg(feature = "contract-support")]
 use crate::{
     constants::{
-        ARG_ADDRESS, DICT_ADDRESSES, DICT_START_TIME, DICT_TRANSFERRED_AMOUNT, DICT_VESTING_AMOUNT,
-        DICT_VESTING_INFO, DICT_VESTING_STATUS, ENTRY_POINT_BALANCE_OF,
+        ARG_ADDRESS, ARG_AMOUNT, ARG_OWNER, ARG_PACKAGE_HASH, ARG_RECIPIENT, ARG_SPENDER,
+        ARG_TREASURY_ADDRESS, ARG_VESTING_MODE, DICT_ADDRESSES, DICT_START_TIME,
+        DICT_TRANSFERRED_AMOUNT, DICT_VESTING_ADDRESSES, DICT_VESTING_AMOUNT, DICT_VESTING_GRANTS,
+        DICT_VESTING_INFO, DICT_VESTING_RECORDS, ENTRY_POINT_ALLOWANCE, ENTRY_POINT_BALANCE_OF,
+        ENTRY_POINT_TRANSFER_FROM, GLOBAL_VESTING_STATE, MONTH_IN_SECONDS,
+    },
+    enums::{VestingMode, VESTING_DUST_RECIPIENT},
+    utils::{
+        add_calendar_months, calendar_months_in_period, elapsed_calendar_months,
+        get_cowl_cep18_contract_package_hash, get_dictionary_value_from_key, get_stored_value,
+        get_stored_value_with_user_errors, set_dictionary_value_for_key,
     },
-    enums::{VESTING_INFO, VESTING_PERCENTAGES},
+};
+use crate::{
+    constants::{BASIS_POINTS_DENOMINATOR, VESTING_GRANT_RECORD_VERSION, VESTING_RECORD_VERSION},
+    enums::{PeriodMode, ReleaseCurve, VestingType, VESTING_INFO, VESTING_SHARES},
     error::VestingError,
-    utils::{get_dictionary_value_from_key, set_dictionary_value_for_key},
+    utils::display_human_readable_duration,
 };
 #[cfg(feature = "contract-support")]
 use alloc::string::ToString;
-use alloc::{fmt, string::String, vec::Vec};
+use alloc::{fmt, string::String, vec, vec::Vec};
 #[cfg(feature = "contract-support")]
 use casper_contract::{
-    contract_api::runtime::{call_versioned_contract, get_blocktime, ret},
+    contract_api::{
+        runtime::{call_versioned_contract, get_blocktime, get_key, put_key, ret, revert},
+        storage,
+    },
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
@@ -27,6 +38,7 @@ use casper_types::{
 #[cfg(feature = "contract-support")]
 use casper_types::{runtime_args, CLValue, ContractPackageHash, RuntimeArgs};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use strum::IntoEnumIterator;
 use time::Duration;
 
 pub trait VestingData: Sized {
@@ -51,7 +63,16 @@ impl VestingData for String {
 pub struct VestingInfo {
     pub vesting_type: VestingType,
     pub maybe_vesting_address_key: Option<Key>,
+    /// Every address sharing the schedule, the allocation address first, their combined outflow
+    /// is capped by the schedule
+    pub vesting_address_keys: Vec<Key>,
     pub vesting_duration: Option<Duration>,
+    pub cliff_duration: Option<Duration>,
+    pub vesting_period: Duration,
+    pub period_mode: PeriodMode,
+    pub release_curve: ReleaseCurve,
+    /// Share of the allocation unlocked at start time, in basis points
+    pub initial_unlock_share: u16,
 }
 
 impl VestingInfo {
@@ -59,26 +80,45 @@ impl VestingInfo {
     fn fmt_inner(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "VestingInfo {{ vesting_type: {:?}, vesting_address_key: {:?}, vesting_duration: {:?}, vesting_period: {:?} }}",
+            "VestingInfo {{ vesting_type: {:?}, vesting_address_key: {:?}, vesting_address_keys: {:?}, vesting_duration: {:?}, cliff_duration: {:?}, vesting_period: {:?}, period_mode: {:?}, release_curve: {:?}, initial_unlock_share: {:?} }}",
             self.vesting_type,
             self.maybe_vesting_address_key,
+            self.vesting_address_keys,
             self.vesting_duration.map(|d| d.whole_seconds() as u64),
-            VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64,
+            self.cliff_duration.map(|d| d.whole_seconds() as u64),
+            self.vesting_period.whole_seconds() as u64,
+            self.period_mode,
+            self.release_curve,
+            self.initial_unlock_share,
         )
     }
 
     pub fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
         let (vesting_type, rem) = VestingType::from_bytes(bytes)?;
         let (maybe_vesting_address_key, rem) = Option::<Key>::from_bytes(rem)?;
+        let (vesting_address_keys, rem) = Vec::<Key>::from_bytes(rem)?;
         let (vesting_duration_opt, rem) = Option::<u64>::from_bytes(rem)?;
+        let (cliff_duration_opt, rem) = Option::<u64>::from_bytes(rem)?;
+        let (vesting_period, rem) = u64::from_bytes(rem)?;
+        let (period_mode, rem) = PeriodMode::from_bytes(rem)?;
+        let (release_curve, rem) = ReleaseCurve::from_bytes(rem)?;
+        let (initial_unlock_share, rem) = u16::from_bytes(rem)?;
 
         let vesting_duration = vesting_duration_opt.map(|seconds| Duration::new(seconds as i64, 0));
+        let cliff_duration = cliff_duration_opt.map(|seconds| Duration::new(seconds as i64, 0));
+        let vesting_period = Duration::new(vesting_period as i64, 0);
 
         Ok((
             VestingInfo {
                 vesting_type,
                 maybe_vesting_address_key,
+                vesting_address_keys,
                 vesting_duration,
+                cliff_duration,
+                vesting_period,
+                period_mode,
+                release_curve,
+                initial_unlock_share,
             },
             rem,
         ))
@@ -115,21 +155,40 @@ impl ToBytes for VestingInfo {
 
         bytes.extend(self.vesting_type.to_bytes()?);
         bytes.extend(self.maybe_vesting_address_key.to_bytes()?);
+        bytes.extend(self.vesting_address_keys.to_bytes()?);
 
         match self.vesting_duration {
             Some(duration) => bytes.extend(Some(duration.whole_seconds() as u64).to_bytes()?),
             None => bytes.extend(Option::<u64>::None.to_bytes()?),
         }
 
+        match self.cliff_duration {
+            Some(duration) => bytes.extend(Some(duration.whole_seconds() as u64).to_bytes()?),
+            None => bytes.extend(Option::<u64>::None.to_bytes()?),
+        }
+
+        bytes.extend((self.vesting_period.whole_seconds() as u64).to_bytes()?);
+        bytes.extend(self.period_mode.to_bytes()?);
+        bytes.extend(self.release_curve.to_bytes()?);
+        bytes.extend(self.initial_unlock_share.to_bytes()?);
+
         Ok(bytes)
     }
 
     fn serialized_length(&self) -> usize {
         self.vesting_type.serialized_length()
             + self.maybe_vesting_address_key.serialized_length()
+            + self.vesting_address_keys.serialized_length()
             + Option::<u64>::serialized_length(
                 &self.vesting_duration.map(|d| d.whole_seconds() as u64),
             )
+            + Option::<u64>::serialized_length(
+                &self.cliff_duration.map(|d| d.whole_seconds() as u64),
+            )
+            + (self.vesting_period.whole_seconds() as u64).serialized_length()
+            + self.period_mode.serialized_length()
+            + self.release_curve.serialized_length()
+            + self.initial_unlock_share.serialized_length()
     }
 }
 
@@ -139,6 +198,586 @@ impl CLTyped for VestingInfo {
     }
 }
 
+/// One row of the allocation table accepted at install: the share of the total supply
+/// allocated to a vesting type and the schedule it vests on.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct VestingConfig {
+    pub vesting_type: VestingType,
+    /// Share of the total supply in basis points
+    pub vesting_share: u16,
+    /// Unix timestamp in seconds vesting starts at, install block time if not set
+    pub start_time: Option<u64>,
+    pub vesting_duration: Option<Duration>,
+    pub cliff_duration: Option<Duration>,
+    pub vesting_period: Duration,
+    pub period_mode: PeriodMode,
+    pub release_curve: ReleaseCurve,
+    /// Share of the allocation unlocked at start time, in basis points
+    pub initial_unlock_share: u16,
+    /// Unlock dates of a `ReleaseCurve::Tranches` schedule, empty for other curves
+    pub tranches: Vec<VestingTranche>,
+    /// Milestones of a `ReleaseCurve::Milestones` schedule, empty for other curves
+    pub milestones: Vec<VestingMilestone>,
+}
+
+impl VestingConfig {
+    pub fn to_vesting_info(&self, maybe_vesting_address_key: Option<Key>) -> VestingInfo {
+        VestingInfo {
+            vesting_type: self.vesting_type,
+            maybe_vesting_address_key,
+            vesting_address_keys: maybe_vesting_address_key.into_iter().collect(),
+            vesting_duration: self.vesting_duration,
+            cliff_duration: self.cliff_duration,
+            vesting_period: self.vesting_period,
+            period_mode: self.period_mode,
+            release_curve: self.release_curve,
+            initial_unlock_share: self.initial_unlock_share,
+        }
+    }
+}
+
+impl ToBytes for VestingConfig {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+
+        bytes.extend(self.vesting_type.to_bytes()?);
+        bytes.extend(self.vesting_share.to_bytes()?);
+        bytes.extend(self.start_time.to_bytes()?);
+        bytes.extend(
+            self.vesting_duration
+                .map(|d| d.whole_seconds() as u64)
+                .to_bytes()?,
+        );
+        bytes.extend(
+            self.cliff_duration
+                .map(|d| d.whole_seconds() as u64)
+                .to_bytes()?,
+        );
+        bytes.extend((self.vesting_period.whole_seconds() as u64).to_bytes()?);
+        bytes.extend(self.period_mode.to_bytes()?);
+        bytes.extend(self.release_curve.to_bytes()?);
+        bytes.extend(self.initial_unlock_share.to_bytes()?);
+        bytes.extend(self.tranches.to_bytes()?);
+        bytes.extend(self.milestones.to_bytes()?);
+
+        Ok(bytes)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.vesting_type.serialized_length()
+            + self.vesting_share.serialized_length()
+            + self.start_time.serialized_length()
+            + self
+                .vesting_duration
+                .map(|d| d.whole_seconds() as u64)
+                .serialized_length()
+            + self
+                .cliff_duration
+                .map(|d| d.whole_seconds() as u64)
+                .serialized_length()
+            + (self.vesting_period.whole_seconds() as u64).serialized_length()
+            + self.period_mode.serialized_length()
+            + self.release_curve.serialized_length()
+            + self.initial_unlock_share.serialized_length()
+            + self.tranches.serialized_length()
+            + self.milestones.serialized_length()
+    }
+}
+
+impl FromBytes for VestingConfig {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (vesting_type, rem) = VestingType::from_bytes(bytes)?;
+        let (vesting_share, rem) = u16::from_bytes(rem)?;
+        let (start_time, rem) = Option::<u64>::from_bytes(rem)?;
+        let (vesting_duration, rem) = Option::<u64>::from_bytes(rem)?;
+        let (cliff_duration, rem) = Option::<u64>::from_bytes(rem)?;
+        let (vesting_period, rem) = u64::from_bytes(rem)?;
+        let (period_mode, rem) = PeriodMode::from_bytes(rem)?;
+        let (release_curve, rem) = ReleaseCurve::from_bytes(rem)?;
+        let (initial_unlock_share, rem) = u16::from_bytes(rem)?;
+        let (tranches, rem) = Vec::<VestingTranche>::from_bytes(rem)?;
+        let (milestones, rem) = Vec::<VestingMilestone>::from_bytes(rem)?;
+
+        Ok((
+            VestingConfig {
+                vesting_type,
+                vesting_share,
+                start_time,
+                vesting_duration: vesting_duration.map(|seconds| Duration::new(seconds as i64, 0)),
+                cliff_duration: cliff_duration.map(|seconds| Duration::new(seconds as i64, 0)),
+                vesting_period: Duration::new(vesting_period as i64, 0),
+                period_mode,
+                release_curve,
+                initial_unlock_share,
+                tranches,
+                milestones,
+            },
+            rem,
+        ))
+    }
+}
+
+impl CLTyped for VestingConfig {
+    fn cl_type() -> CLType {
+        Bytes::cl_type()
+    }
+}
+
+/// Allocation table used when no `vesting_allocations` install arg is provided
+pub fn get_default_vesting_configs() -> Vec<VestingConfig> {
+    VESTING_INFO
+        .iter()
+        .map(|vesting_info| VestingConfig {
+            vesting_type: vesting_info.vesting_type,
+            vesting_share: VESTING_SHARES
+                .iter()
+                .find(|(vesting_type, _)| *vesting_type == vesting_info.vesting_type)
+                .map(|(_, share)| *share)
+                .unwrap_or_default(),
+            start_time: None,
+            vesting_duration: vesting_info.vesting_duration,
+            cliff_duration: vesting_info.cliff_duration,
+            vesting_period: vesting_info.vesting_period,
+            period_mode: vesting_info.period_mode,
+            release_curve: vesting_info.release_curve,
+            initial_unlock_share: vesting_info.initial_unlock_share,
+            tranches: Vec::new(),
+            milestones: Vec::new(),
+        })
+        .collect()
+}
+
+/// Unlock date of a tranche schedule: once `release_offset` has elapsed since start time,
+/// `cumulative_share` of the scheduled amount is vested, in basis points
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct VestingTranche {
+    pub release_offset: Duration,
+    pub cumulative_share: u16,
+}
+
+impl ToBytes for VestingTranche {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+
+        bytes.extend((self.release_offset.whole_seconds() as u64).to_bytes()?);
+        bytes.extend(self.cumulative_share.to_bytes()?);
+
+        Ok(bytes)
+    }
+
+    fn serialized_length(&self) -> usize {
+        (self.release_offset.whole_seconds() as u64).serialized_length()
+            + self.cumulative_share.serialized_length()
+    }
+}
+
+impl FromBytes for VestingTranche {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (release_offset, rem) = u64::from_bytes(bytes)?;
+        let (cumulative_share, rem) = u16::from_bytes(rem)?;
+
+        Ok((
+            VestingTranche {
+                release_offset: Duration::new(release_offset as i64, 0),
+                cumulative_share,
+            },
+            rem,
+        ))
+    }
+}
+
+impl CLTyped for VestingTranche {
+    fn cl_type() -> CLType {
+        Bytes::cl_type()
+    }
+}
+
+/// Named milestone of a milestone schedule, `share` of the scheduled amount in basis points
+/// vests once an admin attests it has been reached
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct VestingMilestone {
+    pub name: String,
+    pub share: u16,
+    /// Unix timestamp in seconds the milestone was released at
+    pub released_at: Option<u64>,
+}
+
+impl ToBytes for VestingMilestone {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+
+        bytes.extend(self.name.to_bytes()?);
+        bytes.extend(self.share.to_bytes()?);
+        bytes.extend(self.released_at.to_bytes()?);
+
+        Ok(bytes)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.name.serialized_length()
+            + self.share.serialized_length()
+            + self.released_at.serialized_length()
+    }
+}
+
+impl FromBytes for VestingMilestone {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (name, rem) = <String as FromBytes>::from_bytes(bytes)?;
+        let (share, rem) = u16::from_bytes(rem)?;
+        let (released_at, rem) = Option::<u64>::from_bytes(rem)?;
+
+        Ok((
+            VestingMilestone {
+                name,
+                share,
+                released_at,
+            },
+            rem,
+        ))
+    }
+}
+
+impl CLTyped for VestingMilestone {
+    fn cl_type() -> CLType {
+        Bytes::cl_type()
+    }
+}
+
+/// Checks every vesting type is configured exactly once, with a release period and an initial
+/// unlock within its allocation, and that the shares add up to 100% of the supply
+pub fn validate_vesting_configs(vesting_configs: &[VestingConfig]) -> Result<(), VestingError> {
+    for vesting_type in VestingType::iter() {
+        let occurrences = vesting_configs
+            .iter()
+            .filter(|config| config.vesting_type == vesting_type)
+            .count();
+        if occurrences != 1 {
+            return Err(VestingError::InvalidVestingAllocations);
+        }
+    }
+
+    if vesting_configs.iter().any(|config| {
+        config.vesting_period <= Duration::ZERO
+            || config.initial_unlock_share > BASIS_POINTS_DENOMINATOR
+    }) {
+        return Err(VestingError::InvalidVestingAllocations);
+    }
+
+    if vesting_configs.iter().any(|config| {
+        !are_vesting_tranches_valid(
+            config.release_curve,
+            config.vesting_duration,
+            &config.tranches,
+        )
+    }) {
+        return Err(VestingError::InvalidVestingTranches);
+    }
+
+    if vesting_configs
+        .iter()
+        .any(|config| !are_vesting_milestones_valid(config))
+    {
+        return Err(VestingError::InvalidVestingMilestones);
+    }
+
+    let total_share: u32 = vesting_configs
+        .iter()
+        .map(|config| u32::from(config.vesting_share))
+        .sum();
+    if total_share != u32::from(BASIS_POINTS_DENOMINATOR) {
+        return Err(VestingError::InvalidVestingAllocationsTotal);
+    }
+
+    Ok(())
+}
+
+/// Tranches are only set on tranche schedules, with strictly increasing unlock dates and
+/// non-decreasing shares ending at 100% when the vesting duration ends
+fn are_vesting_tranches_valid(
+    release_curve: ReleaseCurve,
+    vesting_duration: Option<Duration>,
+    tranches: &[VestingTranche],
+) -> bool {
+    if release_curve != ReleaseCurve::Tranches {
+        return tranches.is_empty();
+    }
+
+    let Some(last_tranche) = tranches.last() else {
+        return false;
+    };
+
+    let is_ordered = tranches.windows(2).all(|tranches| {
+        tranches[0].release_offset < tranches[1].release_offset
+            && tranches[0].cumulative_share <= tranches[1].cumulative_share
+    });
+
+    is_ordered
+        && last_tranche.cumulative_share == BASIS_POINTS_DENOMINATOR
+        && vesting_duration == Some(last_tranche.release_offset)
+}
+
+/// Milestones are only set on milestone schedules, with unique names, not released yet and
+/// shares adding up to 100%
+fn are_vesting_milestones_valid(vesting_config: &VestingConfig) -> bool {
+    if vesting_config.release_curve != ReleaseCurve::Milestones {
+        return vesting_config.milestones.is_empty();
+    }
+
+    let milestones = &vesting_config.milestones;
+    let has_unique_names = milestones.iter().enumerate().all(|(index, milestone)| {
+        !milestone.name.is_empty()
+            && milestones[..index]
+                .iter()
+                .all(|previous| previous.name != milestone.name)
+    });
+    let total_share: u32 = milestones
+        .iter()
+        .map(|milestone| u32::from(milestone.share))
+        .sum();
+
+    has_unique_names
+        && milestones
+            .iter()
+            .all(|milestone| milestone.released_at.is_none())
+        && total_share == u32::from(BASIS_POINTS_DENOMINATOR)
+}
+
+/// Vesting schedule registered at runtime for a single beneficiary, funded out of the
+/// allocation of an existing vesting type
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct VestingGrant {
+    pub beneficiary: Key,
+    /// Vesting type whose allocation funds the grant
+    pub funding_type: VestingType,
+    pub amount: U256,
+    /// Unix timestamp in seconds vesting starts at, creation block time if not set
+    pub start_time: Option<u64>,
+    pub vesting_duration: Option<Duration>,
+    pub cliff_duration: Option<Duration>,
+    pub vesting_period: Duration,
+    pub period_mode: PeriodMode,
+    pub release_curve: ReleaseCurve,
+    /// Share of the grant unlocked at start time, in basis points
+    pub initial_unlock_share: u16,
+    /// Unlock dates of a `ReleaseCurve::Tranches` schedule, empty for other curves
+    pub tranches: Vec<VestingTranche>,
+    /// Lets an admin revoke the grant and recover its unvested remainder, custody mode only so
+    /// the remainder never leaves the contract
+    pub is_revocable: bool,
+    /// Unix timestamp in seconds the grant was revoked at, its amount is then the vested amount
+    pub revoke_time: Option<u64>,
+}
+
+impl VestingGrant {
+    pub fn to_vesting_info(&self) -> VestingInfo {
+        VestingInfo {
+            vesting_type: self.funding_type,
+            maybe_vesting_address_key: Some(self.beneficiary),
+            vesting_address_keys: vec![self.beneficiary],
+            vesting_duration: self.vesting_duration,
+            cliff_duration: self.cliff_duration,
+            vesting_period: self.vesting_period,
+            period_mode: self.period_mode,
+            release_curve: self.release_curve,
+            initial_unlock_share: self.initial_unlock_share,
+        }
+    }
+}
+
+impl ToBytes for VestingGrant {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+
+        bytes.extend(self.beneficiary.to_bytes()?);
+        bytes.extend(self.funding_type.to_bytes()?);
+        bytes.extend(self.amount.to_bytes()?);
+        bytes.extend(self.start_time.to_bytes()?);
+        bytes.extend(
+            self.vesting_duration
+                .map(|d| d.whole_seconds() as u64)
+                .to_bytes()?,
+        );
+        bytes.extend(
+            self.cliff_duration
+                .map(|d| d.whole_seconds() as u64)
+                .to_bytes()?,
+        );
+        bytes.extend((self.vesting_period.whole_seconds() as u64).to_bytes()?);
+        bytes.extend(self.period_mode.to_bytes()?);
+        bytes.extend(self.release_curve.to_bytes()?);
+        bytes.extend(self.initial_unlock_share.to_bytes()?);
+        bytes.extend(self.tranches.to_bytes()?);
+        bytes.extend(self.is_revocable.to_bytes()?);
+        bytes.extend(self.revoke_time.to_bytes()?);
+
+        Ok(bytes)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.beneficiary.serialized_length()
+            + self.funding_type.serialized_length()
+            + self.amount.serialized_length()
+            + self.start_time.serialized_length()
+            + self
+                .vesting_duration
+                .map(|d| d.whole_seconds() as u64)
+                .serialized_length()
+            + self
+                .cliff_duration
+                .map(|d| d.whole_seconds() as u64)
+                .serialized_length()
+            + (self.vesting_period.whole_seconds() as u64).serialized_length()
+            + self.period_mode.serialized_length()
+            + self.release_curve.serialized_length()
+            + self.initial_unlock_share.serialized_length()
+            + self.tranches.serialized_length()
+            + self.is_revocable.serialized_length()
+            + self.revoke_time.serialized_length()
+    }
+}
+
+impl FromBytes for VestingGrant {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (beneficiary, rem) = Key::from_bytes(bytes)?;
+        let (funding_type, rem) = VestingType::from_bytes(rem)?;
+        let (amount, rem) = <U256 as FromBytes>::from_bytes(rem)?;
+        let (start_time, rem) = Option::<u64>::from_bytes(rem)?;
+        let (vesting_duration, rem) = Option::<u64>::from_bytes(rem)?;
+        let (cliff_duration, rem) = Option::<u64>::from_bytes(rem)?;
+        let (vesting_period, rem) = u64::from_bytes(rem)?;
+        let (period_mode, rem) = PeriodMode::from_bytes(rem)?;
+        let (release_curve, rem) = ReleaseCurve::from_bytes(rem)?;
+        let (initial_unlock_share, rem) = u16::from_bytes(rem)?;
+        let (tranches, rem) = Vec::<VestingTranche>::from_bytes(rem)?;
+        let (is_revocable, rem) = bool::from_bytes(rem)?;
+        let (revoke_time, rem) = Option::<u64>::from_bytes(rem)?;
+
+        Ok((
+            VestingGrant {
+                beneficiary,
+                funding_type,
+                amount,
+                start_time,
+                vesting_duration: vesting_duration.map(|seconds| Duration::new(seconds as i64, 0)),
+                cliff_duration: cliff_duration.map(|seconds| Duration::new(seconds as i64, 0)),
+                vesting_period: Duration::new(vesting_period as i64, 0),
+                period_mode,
+                release_curve,
+                initial_unlock_share,
+                tranches,
+                is_revocable,
+                revoke_time,
+            },
+            rem,
+        ))
+    }
+}
+
+impl CLTyped for VestingGrant {
+    fn cl_type() -> CLType {
+        Bytes::cl_type()
+    }
+}
+
+/// Checks a grant vests a non-zero amount on a valid schedule, milestone schedules are attested
+/// per vesting type and cannot be used by grants
+pub fn validate_vesting_grant(vesting_grant: &VestingGrant) -> Result<(), VestingError> {
+    if vesting_grant.amount.is_zero()
+        || vesting_grant.revoke_time.is_some()
+        || vesting_grant.vesting_period <= Duration::ZERO
+        || vesting_grant.initial_unlock_share > BASIS_POINTS_DENOMINATOR
+        || vesting_grant.release_curve == ReleaseCurve::Milestones
+    {
+        return Err(VestingError::InvalidVestingGrant);
+    }
+
+    if !are_vesting_tranches_valid(
+        vesting_grant.release_curve,
+        vesting_grant.vesting_duration,
+        &vesting_grant.tranches,
+    ) {
+        return Err(VestingError::InvalidVestingTranches);
+    }
+
+    Ok(())
+}
+
+/// Grants are keyed by their beneficiary
+pub fn get_vesting_grant_key(beneficiary: &Key) -> String {
+    get_vesting_address_dictionary_key(beneficiary)
+}
+
+/// Key of an address in the address-keyed dictionaries
+pub fn get_vesting_address_dictionary_key(key: &Key) -> String {
+    hex::encode(key.to_bytes().unwrap_or_default())
+}
+
+/// Schedule held by an address, as found in the address-keyed lookup dictionary
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum VestingAddress {
+    /// Allocation address of a vesting type, or an address sharing its schedule
+    Type(VestingType),
+    /// Beneficiary of a grant
+    Grant,
+    /// Allocation address a vesting type moved away from in transfer mode while it still held a
+    /// balance it had not approved for the move, that balance can only go to the addresses of the
+    /// vesting type
+    Previous(VestingType),
+}
+
+impl ToBytes for VestingAddress {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+
+        match self {
+            VestingAddress::Type(vesting_type) => {
+                bytes.push(0u8);
+                bytes.extend(vesting_type.to_bytes()?);
+            }
+            VestingAddress::Grant => bytes.push(1u8),
+            VestingAddress::Previous(vesting_type) => {
+                bytes.push(2u8);
+                bytes.extend(vesting_type.to_bytes()?);
+            }
+        }
+
+        Ok(bytes)
+    }
+
+    fn serialized_length(&self) -> usize {
+        match self {
+            VestingAddress::Type(vesting_type) | VestingAddress::Previous(vesting_type) => {
+                1 + vesting_type.serialized_length()
+            }
+            VestingAddress::Grant => 1,
+        }
+    }
+}
+
+impl FromBytes for VestingAddress {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (tag, rem) = u8::from_bytes(bytes)?;
+        match tag {
+            0 => {
+                let (vesting_type, rem) = VestingType::from_bytes(rem)?;
+                Ok((VestingAddress::Type(vesting_type), rem))
+            }
+            1 => Ok((VestingAddress::Grant, rem)),
+            2 => {
+                let (vesting_type, rem) = VestingType::from_bytes(rem)?;
+                Ok((VestingAddress::Previous(vesting_type), rem))
+            }
+            _ => Err(Error::Formatting),
+        }
+    }
+}
+
+impl CLTyped for VestingAddress {
+    fn cl_type() -> CLType {
+        Bytes::cl_type()
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub struct VestingAllocation {
     pub vesting_type: VestingType,
@@ -166,32 +805,52 @@ pub struct VestingStatus {
         serialize_with = "serialize_duration",
         deserialize_with = "deserialize_duration"
     )]
+    pub cliff_end: Duration,
+    #[serde(
+        serialize_with = "serialize_duration",
+        deserialize_with = "deserialize_duration"
+    )]
     pub time_until_next_release: Duration,
+    pub initial_unlock_amount: U256,
     pub release_amount_per_period: U256,
     pub released_amount: U256,
     pub elapsed_periods: U256,
     pub available_for_release_amount: U256,
     pub total_to_release_amount: U256,
+    /// Set once the grant has been revoked, vesting is then frozen at the vested amount
+    pub is_revoked: bool,
+    #[serde(
+        serialize_with = "serialize_duration",
+        deserialize_with = "deserialize_duration"
+    )]
+    pub revoke_time: Duration,
+    /// Set while outflows of the vesting type, or of all vesting types, are paused
+    pub is_paused: bool,
 }
 
 impl VestingStatus {
     fn fmt_inner(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "VestingStatus {{ vesting_type: {:?}, total_amount: {:?}, vested_amount: {:?}, is_fully_vested: {:?}, vesting_duration: {:?}, start_time: {:?}, time_until_next_release: {:?}, until_next_release: {:?}, release_amount_per_period: {:?}, released_amount: {:?}, elapsed_periods: {:?}, available_for_release_amount: {:?} , total_to_release_amount: {:?} }}",
+            "VestingStatus {{ vesting_type: {:?}, total_amount: {:?}, vested_amount: {:?}, is_fully_vested: {:?}, vesting_duration: {:?}, start_time: {:?}, cliff_end: {:?}, time_until_next_release: {:?}, until_next_release: {:?}, initial_unlock_amount: {:?}, release_amount_per_period: {:?}, released_amount: {:?}, elapsed_periods: {:?}, available_for_release_amount: {:?} , total_to_release_amount: {:?}, is_revoked: {:?}, revoke_time: {:?}, is_paused: {:?} }}",
             self.vesting_type,
             self.total_amount,
             self.vested_amount,
             self.is_fully_vested,
             self.vesting_duration.whole_seconds(),  // Displaying seconds for duration
             self.start_time.whole_seconds(),  // Displaying seconds for duration
+            self.cliff_end.whole_seconds(),  // Displaying seconds for duration
             self.time_until_next_release.whole_seconds(),  // Displaying seconds for duration
             display_human_readable_duration(self.time_until_next_release),
+            self.initial_unlock_amount,
             self.release_amount_per_period,
             self.released_amount,
             self.elapsed_periods,
             self.available_for_release_amount,
-            self.total_to_release_amount
+            self.total_to_release_amount,
+            self.is_revoked,
+            self.revoke_time.whole_seconds(),  // Displaying seconds for duration
+            self.is_paused,
         )
     }
 }
@@ -232,12 +891,17 @@ impl VestingStatus {
         is_fully_vested: bool,
         vesting_duration: Duration,
         start_time: Duration,
+        cliff_end: Duration,
         time_until_next_release: Duration,
+        initial_unlock_amount: U256,
         release_amount_per_period: U256,
         released_amount: U256,
         elapsed_periods: U256,
         available_for_release_amount: U256,
         total_to_release_amount: U256,
+        is_revoked: bool,
+        revoke_time: Duration,
+        is_paused: bool,
     ) -> Self {
         Self {
             vesting_type,
@@ -246,12 +910,17 @@ impl VestingStatus {
             is_fully_vested,
             vesting_duration,
             start_time,
+            cliff_end,
             time_until_next_release,
+            initial_unlock_amount,
             release_amount_per_period,
             released_amount,
             elapsed_periods,
             available_for_release_amount,
             total_to_release_amount,
+            is_revoked,
+            revoke_time,
+            is_paused,
         }
     }
 
@@ -262,7 +931,9 @@ impl VestingStatus {
         let (is_fully_vested, bytes) = bool::from_bytes(bytes)?;
         let (vesting_duration, bytes) = u64::from_bytes(bytes)?;
         let (start_time, bytes) = u64::from_bytes(bytes)?;
+        let (cliff_end, bytes) = u64::from_bytes(bytes)?;
         let (time_until_next_release, bytes) = u64::from_bytes(bytes)?;
+        let (initial_unlock_amount, bytes) = <casper_types::U256 as FromBytes>::from_bytes(bytes)?;
         let (release_amount_per_period, bytes) =
             <casper_types::U256 as FromBytes>::from_bytes(bytes)?;
         let (released_amount, bytes) = <casper_types::U256 as FromBytes>::from_bytes(bytes)?;
@@ -271,10 +942,15 @@ impl VestingStatus {
             <casper_types::U256 as FromBytes>::from_bytes(bytes)?;
         let (total_to_release_amount, bytes) =
             <casper_types::U256 as FromBytes>::from_bytes(bytes)?;
+        let (is_revoked, bytes) = bool::from_bytes(bytes)?;
+        let (revoke_time, bytes) = u64::from_bytes(bytes)?;
+        let (is_paused, bytes) = bool::from_bytes(bytes)?;
 
         let vesting_duration = Duration::new(vesting_duration as i64, 0);
         let start_time = Duration::new(start_time as i64, 0);
+        let cliff_end = Duration::new(cliff_end as i64, 0);
         let time_until_next_release = Duration::new(time_until_next_release as i64, 0);
+        let revoke_time = Duration::new(revoke_time as i64, 0);
 
         Ok((
             VestingStatus::new(
@@ -284,12 +960,17 @@ impl VestingStatus {
                 is_fully_vested,
                 vesting_duration,
                 start_time,
+                cliff_end,
                 time_until_next_release,
+                initial_unlock_amount,
                 release_amount_per_period,
                 released_amount,
                 elapsed_periods,
                 available_for_release_amount,
                 total_to_release_amount,
+                is_revoked,
+                revoke_time,
+                is_paused,
             ),
             bytes,
         ))
@@ -319,12 +1000,17 @@ impl ToBytes for VestingStatus {
         bytes.extend(self.is_fully_vested.to_bytes()?);
         bytes.extend((self.vesting_duration.whole_seconds() as u64).to_bytes()?);
         bytes.extend((self.start_time.whole_seconds() as u64).to_bytes()?);
+        bytes.extend((self.cliff_end.whole_seconds() as u64).to_bytes()?);
         bytes.extend((self.time_until_next_release.whole_seconds() as u64).to_bytes()?);
+        bytes.extend(self.initial_unlock_amount.to_bytes()?);
         bytes.extend(self.release_amount_per_period.to_bytes()?);
         bytes.extend(self.released_amount.to_bytes()?);
         bytes.extend(self.elapsed_periods.to_bytes()?);
         bytes.extend(self.available_for_release_amount.to_bytes()?);
         bytes.extend(self.total_to_release_amount.to_bytes()?);
+        bytes.extend(self.is_revoked.to_bytes()?);
+        bytes.extend((self.revoke_time.whole_seconds() as u64).to_bytes()?);
+        bytes.extend(self.is_paused.to_bytes()?);
         Ok(bytes)
     }
 
@@ -335,12 +1021,17 @@ impl ToBytes for VestingStatus {
             + self.is_fully_vested.serialized_length()
             + (self.vesting_duration.whole_seconds() as u64).serialized_length()
             + (self.start_time.whole_seconds() as u64).serialized_length()
+            + (self.cliff_end.whole_seconds() as u64).serialized_length()
             + (self.time_until_next_release.whole_seconds() as u64).serialized_length()
+            + self.initial_unlock_amount.serialized_length()
             + self.release_amount_per_period.serialized_length()
             + self.released_amount.serialized_length()
             + self.elapsed_periods.serialized_length()
             + self.available_for_release_amount.serialized_length()
             + self.total_to_release_amount.serialized_length()
+            + self.is_revoked.serialized_length()
+            + (self.revoke_time.whole_seconds() as u64).serialized_length()
+            + self.is_paused.serialized_length()
     }
 }
 
@@ -350,217 +1041,1305 @@ impl FromBytes for VestingStatus {
     }
 }
 
-#[cfg(feature = "contract-support")]
-pub fn ret_vesting_status(vesting_type: VestingType) {
-    let vesting_status = update_vesting_status(vesting_type);
-    let result = CLValue::from_t(vesting_status).unwrap_or_revert();
-    ret(result);
+/// Whole state of a vesting type, stored as a single dictionary entry so a status computation
+/// or a filtered transfer reads it once and writes it at most once
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct VestingRecord {
+    /// Layout version of the serialized record
+    pub version: u8,
+    pub vesting_info: VestingInfo,
+    pub start_time: u64,
+    pub vesting_amount: U256,
+    pub transferred_amount: U256,
+    pub vesting_tranches: Vec<VestingTranche>,
+    pub vesting_milestones: Vec<VestingMilestone>,
+    /// Status as of the last write of the record
+    pub vesting_status: VestingStatus,
+    /// Outflows of the vesting type, and of the grants it funds, are paused
+    pub is_paused: bool,
+    /// Unix timestamp in seconds the vesting type, and the grants it funds, are fully vested from
+    pub acceleration_time: Option<u64>,
 }
 
-#[cfg(feature = "contract-support")]
-pub fn update_vesting_status(vesting_type: VestingType) -> VestingStatus {
-    let vesting_status = get_vesting_status_by_type(vesting_type);
-
-    set_dictionary_value_for_key(
-        DICT_VESTING_STATUS,
-        &vesting_status.vesting_type.to_string(),
-        &vesting_status,
-    );
-    vesting_status
-}
+impl ToBytes for VestingRecord {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
 
-#[cfg(feature = "contract-support")]
-pub fn ret_vesting_info(vesting_type: VestingType) {
-    let vesting_info = get_vesting_info_by_type(&vesting_type)
-        .unwrap_or_revert_with(VestingError::InvalidVestingType);
+        bytes.extend(self.version.to_bytes()?);
+        bytes.extend(self.vesting_info.to_bytes()?);
+        bytes.extend(self.start_time.to_bytes()?);
+        bytes.extend(self.vesting_amount.to_bytes()?);
+        bytes.extend(self.transferred_amount.to_bytes()?);
+        bytes.extend(self.vesting_tranches.to_bytes()?);
+        bytes.extend(self.vesting_milestones.to_bytes()?);
+        bytes.extend(self.vesting_status.to_bytes()?);
+        bytes.extend(self.is_paused.to_bytes()?);
+        bytes.extend(self.acceleration_time.to_bytes()?);
 
-    set_dictionary_value_for_key(DICT_VESTING_INFO, &vesting_type.to_string(), &vesting_info);
+        Ok(bytes)
+    }
 
-    let result = CLValue::from_t(vesting_info).unwrap_or_revert();
+    fn serialized_length(&self) -> usize {
+        self.version.serialized_length()
+            + self.vesting_info.serialized_length()
+            + self.start_time.serialized_length()
+            + self.vesting_amount.serialized_length()
+            + self.transferred_amount.serialized_length()
+            + self.vesting_tranches.serialized_length()
+            + self.vesting_milestones.serialized_length()
+            + self.vesting_status.serialized_length()
+            + self.is_paused.serialized_length()
+            + self.acceleration_time.serialized_length()
+    }
+}
+
+impl FromBytes for VestingRecord {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (version, rem) = u8::from_bytes(bytes)?;
+        if version != VESTING_RECORD_VERSION {
+            return Err(Error::Formatting);
+        }
+
+        let (vesting_info, rem) = VestingInfo::from_bytes(rem)?;
+        let (start_time, rem) = u64::from_bytes(rem)?;
+        let (vesting_amount, rem) = <U256 as FromBytes>::from_bytes(rem)?;
+        let (transferred_amount, rem) = <U256 as FromBytes>::from_bytes(rem)?;
+        let (vesting_tranches, rem) = Vec::<VestingTranche>::from_bytes(rem)?;
+        let (vesting_milestones, rem) = Vec::<VestingMilestone>::from_bytes(rem)?;
+        let (vesting_status, rem) = VestingStatus::from_bytes(rem)?;
+        let (is_paused, rem) = bool::from_bytes(rem)?;
+        let (acceleration_time, rem) = Option::<u64>::from_bytes(rem)?;
+
+        Ok((
+            VestingRecord {
+                version,
+                vesting_info,
+                start_time,
+                vesting_amount,
+                transferred_amount,
+                vesting_tranches,
+                vesting_milestones,
+                vesting_status,
+                is_paused,
+                acceleration_time,
+            },
+            rem,
+        ))
+    }
+}
+
+impl CLTyped for VestingRecord {
+    fn cl_type() -> CLType {
+        Bytes::cl_type()
+    }
+}
+
+/// Whole state of a grant, stored as a single dictionary entry keyed by its beneficiary
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct VestingGrantRecord {
+    /// Layout version of the serialized record
+    pub version: u8,
+    pub vesting_grant: VestingGrant,
+    /// Amount of the grant released so far
+    pub transferred_amount: U256,
+    /// Status as of the last write of the record
+    pub vesting_status: VestingStatus,
+}
+
+impl ToBytes for VestingGrantRecord {
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+
+        bytes.extend(self.version.to_bytes()?);
+        bytes.extend(self.vesting_grant.to_bytes()?);
+        bytes.extend(self.transferred_amount.to_bytes()?);
+        bytes.extend(self.vesting_status.to_bytes()?);
+
+        Ok(bytes)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.version.serialized_length()
+            + self.vesting_grant.serialized_length()
+            + self.transferred_amount.serialized_length()
+            + self.vesting_status.serialized_length()
+    }
+}
+
+impl FromBytes for VestingGrantRecord {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (version, rem) = u8::from_bytes(bytes)?;
+        if version != VESTING_GRANT_RECORD_VERSION {
+            return Err(Error::Formatting);
+        }
+
+        let (vesting_grant, rem) = VestingGrant::from_bytes(rem)?;
+        let (transferred_amount, rem) = <U256 as FromBytes>::from_bytes(rem)?;
+        let (vesting_status, rem) = VestingStatus::from_bytes(rem)?;
+
+        Ok((
+            VestingGrantRecord {
+                version,
+                vesting_grant,
+                transferred_amount,
+                vesting_status,
+            },
+            rem,
+        ))
+    }
+}
+
+impl CLTyped for VestingGrantRecord {
+    fn cl_type() -> CLType {
+        Bytes::cl_type()
+    }
+}
+
+#[cfg(feature = "contract-support")]
+pub fn ret_vesting_status(vesting_type: VestingType) {
+    let vesting_status = update_vesting_status(vesting_type, &get_global_vesting_state());
+    let result = CLValue::from_t(vesting_status).unwrap_or_revert();
+    ret(result);
+}
+
+#[cfg(feature = "contract-support")]
+pub fn ret_vesting_grant_status(beneficiary: &Key) {
+    let mut vesting_grant_record = get_vesting_grant_record(beneficiary)
+        .unwrap_or_revert_with(VestingError::InvalidBeneficiary);
+    let funding_record = get_vesting_record(vesting_grant_record.vesting_grant.funding_type);
+    update_vesting_grant_record(
+        &mut vesting_grant_record,
+        &funding_record,
+        &get_global_vesting_state(),
+    );
+    let result = CLValue::from_t(vesting_grant_record.vesting_status).unwrap_or_revert();
+    ret(result);
+}
+
+#[cfg(feature = "contract-support")]
+fn update_vesting_status(
+    vesting_type: VestingType,
+    global_vesting_state: &GlobalVestingState,
+) -> VestingStatus {
+    let mut vesting_record = get_vesting_record(vesting_type);
+    update_vesting_record(&mut vesting_record, global_vesting_state);
+    vesting_record.vesting_status
+}
+
+/// State set for all vesting types at once, read once per call and passed down to the status
+/// computations
+#[cfg(feature = "contract-support")]
+#[derive(Clone, Copy, Default)]
+pub struct GlobalVestingState {
+    pub is_paused: bool,
+    pub acceleration_time: Option<u64>,
+}
+
+#[cfg(feature = "contract-support")]
+pub fn get_global_vesting_state() -> GlobalVestingState {
+    if get_key(GLOBAL_VESTING_STATE).is_none() {
+        return GlobalVestingState::default();
+    }
+
+    let (is_paused, acceleration_time) = get_stored_value(GLOBAL_VESTING_STATE);
+    GlobalVestingState {
+        is_paused,
+        acceleration_time,
+    }
+}
+
+#[cfg(feature = "contract-support")]
+fn set_global_vesting_state(global_vesting_state: &GlobalVestingState) {
+    put_key(
+        GLOBAL_VESTING_STATE,
+        storage::new_uref((
+            global_vesting_state.is_paused,
+            global_vesting_state.acceleration_time,
+        ))
+        .into(),
+    );
+}
+
+/// Outflows of a vesting type, and of the grants it funds, stop while it or all vesting types
+/// are paused
+#[cfg(feature = "contract-support")]
+fn is_vesting_paused(
+    vesting_record: &VestingRecord,
+    global_vesting_state: &GlobalVestingState,
+) -> bool {
+    global_vesting_state.is_paused || vesting_record.is_paused
+}
+
+/// Pauses or unpauses a vesting type, all vesting types if none is given, and refreshes the
+/// stored statuses reporting it
+#[cfg(feature = "contract-support")]
+pub fn set_vesting_pause(maybe_vesting_type: Option<VestingType>, paused: bool) {
+    let mut global_vesting_state = get_global_vesting_state();
+
+    let revert_unchanged = |is_paused: bool| {
+        if is_paused == paused {
+            revert(if paused {
+                VestingError::VestingAlreadyPaused
+            } else {
+                VestingError::VestingNotPaused
+            });
+        }
+    };
+
+    match maybe_vesting_type {
+        Some(vesting_type) => {
+            let mut vesting_record = get_vesting_record(vesting_type);
+            revert_unchanged(vesting_record.is_paused);
+
+            vesting_record.is_paused = paused;
+            update_vesting_record(&mut vesting_record, &global_vesting_state);
+        }
+        None => {
+            revert_unchanged(global_vesting_state.is_paused);
+
+            global_vesting_state.is_paused = paused;
+            set_global_vesting_state(&global_vesting_state);
+
+            for vesting_info in get_vesting_info() {
+                update_vesting_status(vesting_info.vesting_type, &global_vesting_state);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "contract-support")]
+pub fn get_vesting_record(vesting_type: VestingType) -> VestingRecord {
+    get_dictionary_value_from_key(DICT_VESTING_RECORDS, &vesting_type.to_string())
+        .unwrap_or_revert_with(VestingError::InvalidVestingType)
+}
+
+/// Writes the record as is, callers changing what its status depends on refresh it first
+#[cfg(feature = "contract-support")]
+pub fn set_vesting_record(vesting_record: &mut VestingRecord) {
+    // Records are always written in the current layout
+    vesting_record.version = VESTING_RECORD_VERSION;
+
+    set_dictionary_value_for_key(
+        DICT_VESTING_RECORDS,
+        &vesting_record.vesting_info.vesting_type.to_string(),
+        vesting_record,
+    );
+}
+
+/// Recomputes the status of a record after a change to its schedule or amounts
+#[cfg(feature = "contract-support")]
+pub fn refresh_vesting_status(vesting_record: &mut VestingRecord) {
+    vesting_record.vesting_status =
+        get_vesting_status_by_record(vesting_record, &get_global_vesting_state());
+}
+
+/// Recomputes the status of the record with the global state already read, then writes it
+#[cfg(feature = "contract-support")]
+fn update_vesting_record(
+    vesting_record: &mut VestingRecord,
+    global_vesting_state: &GlobalVestingState,
+) {
+    vesting_record.vesting_status =
+        get_vesting_status_by_record(vesting_record, global_vesting_state);
+    set_vesting_record(vesting_record);
+}
+
+/// Records a release in a record, adjusting the status computed for it just before rather than
+/// computing it again
+#[cfg(feature = "contract-support")]
+fn release_vesting_record_amount(
+    vesting_record: &mut VestingRecord,
+    mut vesting_status: VestingStatus,
+    amount: U256,
+) {
+    apply_vesting_release(&mut vesting_status, amount);
+    vesting_record.transferred_amount = vesting_status.released_amount;
+    vesting_record.vesting_status = vesting_status;
+    set_vesting_record(vesting_record);
+}
+
+/// Same as `release_vesting_record_amount` for the record of a grant
+#[cfg(feature = "contract-support")]
+fn release_vesting_grant_amount(
+    vesting_grant_record: &mut VestingGrantRecord,
+    mut vesting_status: VestingStatus,
+    amount: U256,
+) {
+    apply_vesting_release(&mut vesting_status, amount);
+    vesting_grant_record.transferred_amount = vesting_status.released_amount;
+    vesting_grant_record.vesting_status = vesting_status;
+    set_vesting_grant_record(vesting_grant_record);
+}
+
+/// A release of at most the available amount leaves the schedule untouched, only the released
+/// amounts move
+#[cfg(feature = "contract-support")]
+fn apply_vesting_release(vesting_status: &mut VestingStatus, amount: U256) {
+    vesting_status.released_amount += amount;
+    vesting_status.available_for_release_amount = vesting_status
+        .available_for_release_amount
+        .saturating_sub(amount);
+    vesting_status.total_to_release_amount = vesting_status
+        .total_to_release_amount
+        .saturating_sub(amount);
+}
+
+/// Record of a vesting type at install, its status is computed when it is first written
+#[cfg(feature = "contract-support")]
+pub fn new_vesting_record(
+    vesting_info: VestingInfo,
+    start_time: u64,
+    vesting_amount: U256,
+    vesting_tranches: Vec<VestingTranche>,
+    vesting_milestones: Vec<VestingMilestone>,
+) -> VestingRecord {
+    let vesting_status = get_vesting_status(
+        &vesting_info,
+        start_time,
+        vesting_amount,
+        U256::zero(),
+        &vesting_tranches,
+        &vesting_milestones,
+        false,
+    );
+
+    VestingRecord {
+        version: VESTING_RECORD_VERSION,
+        vesting_info,
+        start_time,
+        vesting_amount,
+        transferred_amount: U256::zero(),
+        vesting_tranches,
+        vesting_milestones,
+        vesting_status,
+        is_paused: false,
+        acceleration_time: None,
+    }
+}
+
+/// First release layout of the vesting info, the schedule itself was not stored
+#[cfg(feature = "contract-support")]
+struct LegacyVestingInfo {
+    maybe_vesting_address_key: Option<Key>,
+    vesting_duration: Option<u64>,
+}
+
+#[cfg(feature = "contract-support")]
+impl FromBytes for LegacyVestingInfo {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), Error> {
+        let (_, rem) = VestingType::from_bytes(bytes)?;
+        let (maybe_vesting_address_key, rem) = Option::<Key>::from_bytes(rem)?;
+        let (vesting_duration, rem) = Option::<u64>::from_bytes(rem)?;
+
+        Ok((
+            LegacyVestingInfo {
+                maybe_vesting_address_key,
+                vesting_duration,
+            },
+            rem,
+        ))
+    }
+}
+
+#[cfg(feature = "contract-support")]
+impl CLTyped for LegacyVestingInfo {
+    fn cl_type() -> CLType {
+        Bytes::cl_type()
+    }
+}
+
+/// Builds the vesting records from the per-type dictionaries of the first release. Its
+/// schedules released in monthly steps from start time, without cliff nor initial unlock.
+#[cfg(feature = "contract-support")]
+pub fn migrate_vesting_records() {
+    for vesting_type in VestingType::iter() {
+        let vesting_type_key = vesting_type.to_string();
+
+        let Some(legacy_vesting_info) = get_dictionary_value_from_key::<LegacyVestingInfo>(
+            DICT_VESTING_INFO,
+            &vesting_type_key,
+        ) else {
+            continue;
+        };
+
+        let maybe_vesting_address_key =
+            get_dictionary_value_from_key::<Key>(DICT_ADDRESSES, &vesting_type_key)
+                .or(legacy_vesting_info.maybe_vesting_address_key);
+
+        let vesting_info = VestingInfo {
+            vesting_type,
+            maybe_vesting_address_key,
+            vesting_address_keys: maybe_vesting_address_key.into_iter().collect(),
+            vesting_duration: legacy_vesting_info
+                .vesting_duration
+                .map(|seconds| Duration::new(seconds as i64, 0)),
+            cliff_duration: None,
+            vesting_period: Duration::new(MONTH_IN_SECONDS as i64, 0),
+            period_mode: PeriodMode::Fixed,
+            release_curve: ReleaseCurve::Stepwise,
+            initial_unlock_share: 0,
+        };
+
+        let mut vesting_record = new_vesting_record(
+            vesting_info,
+            get_dictionary_value_from_key(DICT_START_TIME, &vesting_type_key).unwrap_or_default(),
+            get_dictionary_value_from_key(DICT_VESTING_AMOUNT, &vesting_type_key)
+                .unwrap_or_default(),
+            Vec::new(),
+            Vec::new(),
+        );
+        vesting_record.transferred_amount =
+            get_dictionary_value_from_key(DICT_TRANSFERRED_AMOUNT, &vesting_type_key)
+                .unwrap_or_default();
+
+        refresh_vesting_status(&mut vesting_record);
+        set_vesting_record(&mut vesting_record);
+    }
+}
+
+#[cfg(feature = "contract-support")]
+pub fn ret_vesting_info(vesting_type: VestingType) {
+    let vesting_info = get_vesting_info_by_type(&vesting_type)
+        .unwrap_or_revert_with(VestingError::InvalidVestingType);
+
+    let result = CLValue::from_t(vesting_info).unwrap_or_revert();
     ret(result);
 }
 
 #[cfg(feature = "contract-support")]
 pub fn get_vesting_info() -> Vec<VestingInfo> {
-    VESTING_INFO
-        .iter()
-        .map(|vesting_info| VestingInfo {
-            vesting_type: vesting_info.vesting_type,
-            maybe_vesting_address_key: get_dictionary_value_from_key(
-                DICT_ADDRESSES,
-                &vesting_info.vesting_type.to_string(),
-            ),
-            vesting_duration: vesting_info.vesting_duration,
-        })
+    VestingType::iter()
+        .filter_map(|vesting_type| get_vesting_info_by_type(&vesting_type))
         .collect()
 }
 
+/// Single read telling whether an address holds a schedule, so transfers from any other address
+/// are filtered without going through every vesting type
 #[cfg(feature = "contract-support")]
-fn get_vesting_info_by_key(vesting_address_key: &Key) -> Option<VestingInfo> {
-    get_vesting_info()
-        .into_iter()
-        .find(|info| info.maybe_vesting_address_key.as_ref() == Some(vesting_address_key))
+pub fn get_vesting_address(key: &Key) -> Option<VestingAddress> {
+    get_dictionary_value_from_key::<Option<VestingAddress>>(
+        DICT_VESTING_ADDRESSES,
+        &get_vesting_address_dictionary_key(key),
+    )
+    .flatten()
+}
+
+/// Indexes the schedule held by an address, None once the address no longer holds it
+#[cfg(feature = "contract-support")]
+pub fn set_vesting_address(key: &Key, maybe_vesting_address: Option<VestingAddress>) {
+    set_dictionary_value_for_key(
+        DICT_VESTING_ADDRESSES,
+        &get_vesting_address_dictionary_key(key),
+        &maybe_vesting_address,
+    );
+}
+
+/// Whether the key already holds a schedule, as the address of a vesting type or as a grant
+/// beneficiary
+#[cfg(feature = "contract-support")]
+pub fn is_vesting_address(key: &Key) -> bool {
+    get_vesting_address(key).is_some()
+}
+
+/// Adds an address sharing the schedule of a vesting type. Tokens it already holds would fall
+/// under the schedule without its consent, and could never be removed from it, so it has to be
+/// empty.
+#[cfg(feature = "contract-support")]
+pub fn add_shared_vesting_address(vesting_type: VestingType, vesting_address_key: Key) {
+    if is_vesting_address(&vesting_address_key) {
+        revert(VestingError::InvalidVestingAddress);
+    }
+
+    let balance =
+        get_current_balance_for_key(get_cowl_cep18_contract_package_hash(), &vesting_address_key);
+    if !balance.is_zero() {
+        revert(VestingError::VestingAddressNotEmpty);
+    }
+
+    let mut vesting_record = get_vesting_record(vesting_type);
+    vesting_record
+        .vesting_info
+        .vesting_address_keys
+        .push(vesting_address_key);
+
+    set_vesting_record(&mut vesting_record);
+    set_vesting_address(
+        &vesting_address_key,
+        Some(VestingAddress::Type(vesting_type)),
+    );
+}
+
+/// Removes an address sharing the schedule of a vesting type. Its balance would no longer count
+/// against the schedule, so it has to be moved to another address of the vesting type first.
+#[cfg(feature = "contract-support")]
+pub fn remove_shared_vesting_address(vesting_type: VestingType, vesting_address_key: Key) {
+    let mut vesting_record = get_vesting_record(vesting_type);
+
+    // The allocation address itself is moved with a vesting address change, not removed
+    let index = vesting_record
+        .vesting_info
+        .vesting_address_keys
+        .iter()
+        .position(|key| {
+            *key == vesting_address_key
+                && Some(*key) != vesting_record.vesting_info.maybe_vesting_address_key
+        })
+        .unwrap_or_revert_with(VestingError::InvalidVestingAddress);
+
+    let balance =
+        get_current_balance_for_key(get_cowl_cep18_contract_package_hash(), &vesting_address_key);
+    if !balance.is_zero() {
+        revert(VestingError::VestingAddressNotEmpty);
+    }
+
+    vesting_record
+        .vesting_info
+        .vesting_address_keys
+        .remove(index);
+
+    set_vesting_record(&mut vesting_record);
+    set_vesting_address(&vesting_address_key, None);
 }
 
 #[cfg(feature = "contract-support")]
 fn get_vesting_info_by_type(vesting_type: &VestingType) -> Option<VestingInfo> {
-    get_vesting_info()
-        .into_iter()
-        .find(|info| info.vesting_type == *vesting_type)
+    get_dictionary_value_from_key::<VestingRecord>(DICT_VESTING_RECORDS, &vesting_type.to_string())
+        .map(|vesting_record| vesting_record.vesting_info)
+}
+
+#[cfg(feature = "contract-support")]
+fn get_vesting_status(
+    vesting_info: &VestingInfo,
+    start_time: u64,
+    total_amount: U256,
+    released_amount: U256,
+    vesting_tranches: &[VestingTranche],
+    vesting_milestones: &[VestingMilestone],
+    is_paused: bool,
+) -> VestingStatus {
+    let start_time_in_ms: u64 = get_blocktime().into();
+    let current_time = start_time_in_ms.checked_div(1000).unwrap_or_default();
+
+    let cliff_end = calculate_cliff_end(start_time, vesting_info.cliff_duration);
+    let is_cliff_reached = current_time >= cliff_end;
+
+    // The initial unlock is available at start time, the remainder vests on the schedule
+    let initial_unlock_amount =
+        calculate_share_amount(total_amount, vesting_info.initial_unlock_share);
+    let scheduled_amount = total_amount.saturating_sub(initial_unlock_amount);
+
+    let vested_amount = match (vesting_info.vesting_duration, vesting_info.release_curve) {
+        // Vesting addresses are fully locked until start time
+        _ if current_time < start_time => U256::zero(),
+        // Milestone schedules do not depend on the vesting duration
+        (_, ReleaseCurve::Milestones) => {
+            initial_unlock_amount
+                + calculate_milestone_vesting(
+                    start_time,
+                    vesting_info.cliff_duration,
+                    vesting_milestones,
+                    scheduled_amount,
+                    current_time,
+                )
+        }
+        (Some(_), ReleaseCurve::Tranches) => {
+            initial_unlock_amount
+                + calculate_tranche_vesting(
+                    start_time,
+                    vesting_info.cliff_duration,
+                    vesting_tranches,
+                    scheduled_amount,
+                    current_time,
+                )
+        }
+        (Some(duration), release_curve) => {
+            let calculate_vesting = match release_curve {
+                ReleaseCurve::Continuous => calculate_continuous_vesting,
+                ReleaseCurve::BackLoaded => calculate_back_loaded_vesting,
+                _ => calculate_linear_vesting,
+            };
+            initial_unlock_amount
+                + calculate_vesting(
+                    start_time,
+                    duration,
+                    vesting_info.cliff_duration,
+                    vesting_info.vesting_period,
+                    vesting_info.period_mode,
+                    scheduled_amount,
+                    current_time,
+                )
+        }
+        (None, _) => U256::zero(), // Default to no vesting if duration is None
+    };
+
+    let is_fully_vested = (vesting_info.vesting_duration.is_none()
+        && vesting_info.release_curve != ReleaseCurve::Milestones
+        && is_cliff_reached)
+        || vested_amount == total_amount;
+    let time_until_next_release = if is_fully_vested {
+        Duration::ZERO
+    } else if !is_cliff_reached {
+        Duration::seconds(cliff_end.saturating_sub(current_time) as i64)
+    } else if vesting_info.release_curve == ReleaseCurve::Tranches {
+        calculate_time_until_next_tranche(start_time, vesting_tranches, current_time)
+    } else if vesting_info.release_curve == ReleaseCurve::Milestones {
+        // Next release depends on the next milestone attestation
+        Duration::ZERO
+    } else {
+        vesting_info
+            .vesting_duration
+            .map_or(Duration::ZERO, |duration| {
+                calculate_time_until_next_release(
+                    start_time,
+                    duration,
+                    vesting_info.vesting_period,
+                    vesting_info.period_mode,
+                    current_time,
+                )
+            })
+    };
+
+    let release_amount_per_period =
+        match (vesting_info.vesting_duration, vesting_info.release_curve) {
+            // Tranche schedules report the amount released by the next tranche
+            (Some(_), ReleaseCurve::Tranches) => calculate_next_tranche_amount(
+                start_time,
+                vesting_tranches,
+                scheduled_amount,
+                current_time,
+            ),
+            (Some(duration), _) => calculate_release_per_period(
+                scheduled_amount,
+                duration,
+                vesting_info.vesting_period,
+            ),
+            (None, _) => U256::zero(),
+        };
+
+    let elapsed_periods = if vesting_info.release_curve == ReleaseCurve::Tranches {
+        U256::from(calculate_reached_tranches(
+            start_time,
+            vesting_tranches,
+            current_time,
+        ))
+    } else if vesting_info.release_curve == ReleaseCurve::Milestones {
+        U256::from(
+            vesting_milestones
+                .iter()
+                .filter(|milestone| milestone.released_at.is_some())
+                .count(),
+        )
+    } else {
+        U256::from(calculate_elapsed_periods(
+            start_time,
+            vesting_info.vesting_period,
+            vesting_info.period_mode,
+            current_time,
+        ))
+    };
+
+    let expected_released_amount = if is_fully_vested {
+        // if elapsed_periods is above full vesting time we don't want to calculate expected_released_amount based on periods
+        total_amount
+    } else if current_time < start_time {
+        // Nothing is released before start time, not even the initial unlock
+        U256::zero()
+    } else if !is_cliff_reached {
+        // Nothing but the initial unlock is released before the cliff, accrued periods unlock at once when it ends
+        initial_unlock_amount
+    } else {
+        // Released so far according to the release curve
+        vested_amount
+    };
+
+    let available_for_release_amount = if is_fully_vested {
+        expected_released_amount
+            .saturating_sub(released_amount)
+            .min(total_amount)
+    } else if expected_released_amount >= released_amount {
+        expected_released_amount.saturating_sub(released_amount)
+    } else {
+        U256::zero()
+    };
+
+    let total_to_release_amount = total_amount.saturating_sub(released_amount);
+
+    VestingStatus::new(
+        vesting_info.vesting_type,
+        total_amount,
+        vested_amount,
+        is_fully_vested,
+        vesting_info.vesting_duration.unwrap_or(Duration::ZERO),
+        Duration::new(start_time as i64, 0),
+        Duration::new(cliff_end as i64, 0),
+        time_until_next_release,
+        initial_unlock_amount,
+        release_amount_per_period,
+        released_amount,
+        elapsed_periods,
+        available_for_release_amount,
+        total_to_release_amount,
+        false,
+        Duration::ZERO,
+        is_paused,
+    )
+}
+
+#[cfg(feature = "contract-support")]
+fn get_vesting_status_by_record(
+    vesting_record: &VestingRecord,
+    global_vesting_state: &GlobalVestingState,
+) -> VestingStatus {
+    // Calculate the vesting status
+    let mut vesting_status = get_vesting_status(
+        &vesting_record.vesting_info,
+        vesting_record.start_time,
+        vesting_record.vesting_amount,
+        vesting_record.transferred_amount,
+        &vesting_record.vesting_tranches,
+        &vesting_record.vesting_milestones,
+        is_vesting_paused(vesting_record, global_vesting_state),
+    );
+
+    apply_vesting_acceleration(
+        &mut vesting_status,
+        get_vesting_acceleration_time(vesting_record, global_vesting_state),
+    );
+
+    vesting_status
+}
+
+/// Unix timestamp in seconds a vesting type, and the grants it funds, are fully vested from,
+/// the earliest of its own acceleration and the acceleration of all vesting types
+#[cfg(feature = "contract-support")]
+fn get_vesting_acceleration_time(
+    vesting_record: &VestingRecord,
+    global_vesting_state: &GlobalVestingState,
+) -> Option<u64> {
+    match (
+        vesting_record.acceleration_time,
+        global_vesting_state.acceleration_time,
+    ) {
+        (Some(acceleration_time), Some(global_acceleration_time)) => {
+            Some(acceleration_time.min(global_acceleration_time))
+        }
+        (acceleration_time, global_acceleration_time) => {
+            acceleration_time.or(global_acceleration_time)
+        }
+    }
+}
+
+/// Once accelerated the whole amount is vested, the schedule itself is left untouched
+#[cfg(feature = "contract-support")]
+fn apply_vesting_acceleration(vesting_status: &mut VestingStatus, acceleration_time: Option<u64>) {
+    if let Some(acceleration_time) = acceleration_time {
+        let current_time_in_ms: u64 = get_blocktime().into();
+        if current_time_in_ms.checked_div(1000).unwrap_or_default() >= acceleration_time {
+            vesting_status.vested_amount = vesting_status.total_amount;
+            vesting_status.is_fully_vested = true;
+            vesting_status.time_until_next_release = Duration::ZERO;
+            vesting_status.available_for_release_amount = vesting_status.total_to_release_amount;
+        }
+    }
+}
+
+/// Accelerates a vesting type, all vesting types if none is given, to be fully vested from the
+/// acceleration time. Grants follow the acceleration of their funding vesting type.
+/// The acceleration time cannot be in the future, as it could never be brought forward, nor
+/// before the start time of the accelerated vesting type.
+#[cfg(feature = "contract-support")]
+pub fn accelerate_vesting(maybe_vesting_type: Option<VestingType>, acceleration_time: u64) {
+    let current_time_in_ms: u64 = get_blocktime().into();
+    if acceleration_time > current_time_in_ms.checked_div(1000).unwrap_or_default() {
+        revert(VestingError::InvalidAccelerationTime);
+    }
+
+    let mut global_vesting_state = get_global_vesting_state();
+
+    match maybe_vesting_type {
+        Some(vesting_type) => {
+            let mut vesting_record = get_vesting_record(vesting_type);
+            if acceleration_time < vesting_record.start_time {
+                revert(VestingError::InvalidAccelerationTime);
+            }
+            if get_vesting_acceleration_time(&vesting_record, &global_vesting_state).is_some() {
+                revert(VestingError::VestingAlreadyAccelerated);
+            }
+
+            vesting_record.acceleration_time = Some(acceleration_time);
+            update_vesting_record(&mut vesting_record, &global_vesting_state);
+        }
+        None => {
+            if global_vesting_state.acceleration_time.is_some() {
+                revert(VestingError::VestingAlreadyAccelerated);
+            }
+
+            global_vesting_state.acceleration_time = Some(acceleration_time);
+            set_global_vesting_state(&global_vesting_state);
+
+            for vesting_info in get_vesting_info() {
+                update_vesting_status(vesting_info.vesting_type, &global_vesting_state);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "contract-support")]
+pub fn get_vesting_grant_record(beneficiary: &Key) -> Option<VestingGrantRecord> {
+    get_dictionary_value_from_key(DICT_VESTING_GRANTS, &get_vesting_grant_key(beneficiary))
+}
+
+#[cfg(feature = "contract-support")]
+fn set_vesting_grant_record(vesting_grant_record: &VestingGrantRecord) {
+    set_dictionary_value_for_key(
+        DICT_VESTING_GRANTS,
+        &get_vesting_grant_key(&vesting_grant_record.vesting_grant.beneficiary),
+        vesting_grant_record,
+    );
+}
+
+/// Recomputes the status of the grant record, then writes it
+#[cfg(feature = "contract-support")]
+fn update_vesting_grant_record(
+    vesting_grant_record: &mut VestingGrantRecord,
+    funding_record: &VestingRecord,
+    global_vesting_state: &GlobalVestingState,
+) {
+    vesting_grant_record.vesting_status = get_vesting_grant_status(
+        &vesting_grant_record.vesting_grant,
+        vesting_grant_record.transferred_amount,
+        funding_record,
+        global_vesting_state,
+    );
+    set_vesting_grant_record(vesting_grant_record);
+}
+
+#[cfg(feature = "contract-support")]
+fn get_vesting_grant_status(
+    vesting_grant: &VestingGrant,
+    released_amount: U256,
+    funding_record: &VestingRecord,
+    global_vesting_state: &GlobalVestingState,
+) -> VestingStatus {
+    let mut vesting_status = get_vesting_status(
+        &vesting_grant.to_vesting_info(),
+        vesting_grant.start_time.unwrap_or_default(),
+        vesting_grant.amount,
+        released_amount,
+        &vesting_grant.tranches,
+        &[],
+        is_vesting_paused(funding_record, global_vesting_state),
+    );
+
+    apply_vesting_acceleration(
+        &mut vesting_status,
+        get_vesting_acceleration_time(funding_record, global_vesting_state),
+    );
+
+    // A revoked grant is frozen, its amount is what had vested when it was revoked
+    if let Some(revoke_time) = vesting_grant.revoke_time {
+        vesting_status.vested_amount = vesting_grant.amount;
+        vesting_status.is_fully_vested = true;
+        vesting_status.time_until_next_release = Duration::ZERO;
+        vesting_status.available_for_release_amount =
+            vesting_grant.amount.saturating_sub(released_amount);
+        vesting_status.is_revoked = true;
+        vesting_status.revoke_time = Duration::new(revoke_time as i64, 0);
+    }
+
+    vesting_status
+}
+
+/// Registers a grant, moving its amount out of the allocation of the funding vesting type.
+/// In transfer mode the grant amount is moved from the funding vesting address to the
+/// beneficiary, which needs the funding vesting address to have approved the vesting contract
+/// package for it.
+#[cfg(feature = "contract-support")]
+pub fn create_vesting_grant(mut vesting_grant: VestingGrant) -> VestingGrant {
+    validate_vesting_grant(&vesting_grant).unwrap_or_revert();
+
+    // A beneficiary holds a single schedule, either a vesting type or a grant
+    match get_vesting_address(&vesting_grant.beneficiary) {
+        Some(VestingAddress::Type(_)) | Some(VestingAddress::Previous(_)) => {
+            revert(VestingError::InvalidVestingGrant)
+        }
+        Some(VestingAddress::Grant) => revert(VestingError::VestingGrantAlreadyExists),
+        None => {}
+    }
+
+    let current_time_in_ms: u64 = get_blocktime().into();
+    let current_time = current_time_in_ms.checked_div(1000).unwrap_or_default();
+
+    let start_time = vesting_grant.start_time.unwrap_or(current_time);
+    if start_time < current_time {
+        revert(VestingError::InvalidStartTime);
+    }
+    vesting_grant.start_time = Some(start_time);
+
+    // Tokens held by the beneficiary cannot be recovered without its approval
+    let vesting_mode = get_vesting_mode();
+    if vesting_grant.is_revocable && vesting_mode == VestingMode::Transfer {
+        revert(VestingError::RevocableGrantRequiresCustody);
+    }
+
+    // Only the part of the allocation still held by the funding vesting address can be granted
+    let global_vesting_state = get_global_vesting_state();
+    let mut funding_record = get_vesting_record(vesting_grant.funding_type);
+    let funding_status = get_vesting_status_by_record(&funding_record, &global_vesting_state);
+    if vesting_grant.amount > funding_status.total_to_release_amount {
+        revert(VestingError::InsufficientVestingAmount);
+    }
+
+    funding_record.vesting_amount = funding_status.total_amount - vesting_grant.amount;
+    update_vesting_record(&mut funding_record, &global_vesting_state);
+
+    // In custody mode the grant amount stays with the contract until claimed
+    if vesting_mode == VestingMode::Transfer {
+        let funding_address_key = funding_record
+            .vesting_info
+            .maybe_vesting_address_key
+            .unwrap_or_revert_with(VestingError::MissingVestingAddress);
+
+        call_versioned_contract::<()>(
+            get_cowl_cep18_contract_package_hash(),
+            None,
+            ENTRY_POINT_TRANSFER_FROM,
+            runtime_args! {
+                ARG_OWNER => funding_address_key,
+                ARG_RECIPIENT => vesting_grant.beneficiary,
+                ARG_AMOUNT => vesting_grant.amount
+            },
+        );
+    }
+
+    let vesting_status = get_vesting_grant_status(
+        &vesting_grant,
+        U256::zero(),
+        &funding_record,
+        &global_vesting_state,
+    );
+    set_vesting_grant_record(&VestingGrantRecord {
+        version: VESTING_GRANT_RECORD_VERSION,
+        vesting_grant: vesting_grant.clone(),
+        transferred_amount: U256::zero(),
+        vesting_status,
+    });
+    set_vesting_address(&vesting_grant.beneficiary, Some(VestingAddress::Grant));
+
+    vesting_grant
+}
+
+/// Carves a grant out of the Contributor allocation, vesting on the Contributor schedule from
+/// its own start time and cliff
+#[cfg(feature = "contract-support")]
+pub fn create_contributor_vesting_grant(
+    beneficiary: Key,
+    amount: U256,
+    start_time: Option<u64>,
+    cliff_duration: Option<Duration>,
+    is_revocable: bool,
+) -> VestingGrant {
+    let VestingRecord {
+        vesting_info,
+        vesting_tranches: tranches,
+        ..
+    } = get_vesting_record(VestingType::Contributor);
+
+    create_vesting_grant(VestingGrant {
+        beneficiary,
+        funding_type: vesting_info.vesting_type,
+        amount,
+        start_time,
+        vesting_duration: vesting_info.vesting_duration,
+        cliff_duration: cliff_duration.or(vesting_info.cliff_duration),
+        vesting_period: vesting_info.vesting_period,
+        period_mode: vesting_info.period_mode,
+        release_curve: vesting_info.release_curve,
+        initial_unlock_share: vesting_info.initial_unlock_share,
+        tranches,
+        is_revocable,
+        revoke_time: None,
+    })
+}
+
+/// Revokes a grant, freezing it at its vested amount. Returns the grant and its unvested
+/// remainder, held in custody for the contract to send to the treasury address.
+#[cfg(feature = "contract-support")]
+pub fn revoke_vesting_grant(beneficiary: &Key) -> (VestingGrant, U256) {
+    let mut vesting_grant_record = get_vesting_grant_record(beneficiary)
+        .unwrap_or_revert_with(VestingError::InvalidBeneficiary);
+    let vesting_grant = &mut vesting_grant_record.vesting_grant;
+
+    if !vesting_grant.is_revocable {
+        revert(VestingError::VestingGrantNotRevocable);
+    }
+    if vesting_grant.revoke_time.is_some() {
+        revert(VestingError::VestingGrantRevoked);
+    }
+
+    let funding_record = get_vesting_record(vesting_grant.funding_type);
+    let global_vesting_state = get_global_vesting_state();
+    let vesting_status = get_vesting_grant_status(
+        vesting_grant,
+        vesting_grant_record.transferred_amount,
+        &funding_record,
+        &global_vesting_state,
+    );
+    let revoked_amount = vesting_grant
+        .amount
+        .saturating_sub(vesting_status.vested_amount);
+
+    let current_time_in_ms: u64 = get_blocktime().into();
+    vesting_grant.amount = vesting_status.vested_amount;
+    vesting_grant.revoke_time = Some(current_time_in_ms.checked_div(1000).unwrap_or_default());
+
+    update_vesting_grant_record(
+        &mut vesting_grant_record,
+        &funding_record,
+        &global_vesting_state,
+    );
+
+    (vesting_grant_record.vesting_grant, revoked_amount)
+}
+
+/// Address receiving the unvested remainder of revoked grants, if configured
+#[cfg(feature = "contract-support")]
+pub fn get_treasury_address() -> Option<Key> {
+    get_key(ARG_TREASURY_ADDRESS)?;
+
+    Some(get_stored_value_with_user_errors(
+        ARG_TREASURY_ADDRESS,
+        VestingError::MissingTreasuryAddress,
+        VestingError::InvalidTreasuryAddress,
+    ))
+}
+
+/// Whether the vesting schedule of the owner allows the transfer. The released amount is only
+/// recorded when not a preview, the record of the schedule being read once and written at most
+/// once.
+#[cfg(feature = "contract-support")]
+pub fn get_vesting_transfer(
+    owner: Key,
+    recipient: Key,
+    requested_amount: U256,
+    is_preview: bool,
+) -> bool {
+    match get_vesting_address(&owner) {
+        Some(VestingAddress::Type(vesting_type)) => {
+            let global_vesting_state = get_global_vesting_state();
+            let mut vesting_record = get_vesting_record(vesting_type);
+            if is_vesting_paused(&vesting_record, &global_vesting_state) {
+                return false;
+            }
+
+            // Moves between addresses sharing the schedule leave their combined outflow unchanged
+            if vesting_record
+                .vesting_info
+                .vesting_address_keys
+                .contains(&recipient)
+            {
+                return true;
+            }
+
+            let status = get_vesting_status_by_record(&vesting_record, &global_vesting_state);
+
+            if requested_amount <= status.available_for_release_amount {
+                if !is_preview {
+                    release_vesting_record_amount(&mut vesting_record, status, requested_amount);
+                }
+                return true;
+            }
+            status.is_fully_vested
+        }
+        Some(VestingAddress::Grant) => {
+            let mut vesting_grant_record = get_vesting_grant_record(&owner)
+                .unwrap_or_revert_with(VestingError::MissingVestingGrant);
+
+            // Grants follow the pause and acceleration of their funding vesting type
+            let global_vesting_state = get_global_vesting_state();
+            let funding_record =
+                get_vesting_record(vesting_grant_record.vesting_grant.funding_type);
+            if is_vesting_paused(&funding_record, &global_vesting_state) {
+                return false;
+            }
+
+            let status = get_vesting_grant_status(
+                &vesting_grant_record.vesting_grant,
+                vesting_grant_record.transferred_amount,
+                &funding_record,
+                &global_vesting_state,
+            );
+
+            if requested_amount <= status.available_for_release_amount {
+                if !is_preview {
+                    release_vesting_grant_amount(
+                        &mut vesting_grant_record,
+                        status,
+                        requested_amount,
+                    );
+                }
+                return true;
+            }
+            status.is_fully_vested
+        }
+        // The previous address holds part of the allocation until it moves it to the new one
+        Some(VestingAddress::Previous(vesting_type)) => get_vesting_record(vesting_type)
+            .vesting_info
+            .vesting_address_keys
+            .contains(&recipient),
+        None => true, // If owner is not a vesting address, allow transfer
+    }
+}
+
+/// Contracts installed before custody mode keep their allocations in the vesting addresses
+#[cfg(feature = "contract-support")]
+pub fn get_vesting_mode() -> VestingMode {
+    if get_key(ARG_VESTING_MODE).is_none() {
+        return VestingMode::Transfer;
+    }
+
+    get_stored_value_with_user_errors::<u8>(
+        ARG_VESTING_MODE,
+        VestingError::InvalidVestingMode,
+        VestingError::InvalidVestingMode,
+    )
+    .try_into()
+    .unwrap_or_revert()
+}
+
+#[cfg(feature = "contract-support")]
+fn release_available_amount(
+    vesting_record: &mut VestingRecord,
+    global_vesting_state: &GlobalVestingState,
+) -> U256 {
+    let status = get_vesting_status_by_record(vesting_record, global_vesting_state);
+    let amount = status.available_for_release_amount;
+
+    if !amount.is_zero() {
+        release_vesting_record_amount(vesting_record, status, amount);
+    }
+
+    amount
 }
 
+/// Records the amount available to the schedule of the recipient as released, for the contract
+/// to transfer it out in custody mode
 #[cfg(feature = "contract-support")]
-fn get_vesting_status(
-    vesting_info: &VestingInfo,
-    start_time: u64,
-    total_amount: U256,
-) -> VestingStatus {
-    let start_time_in_ms: u64 = get_blocktime().into();
-    let current_time = start_time_in_ms.checked_div(1000).unwrap_or_default();
-
-    #[allow(clippy::match_single_binding)]
-    let vested_amount = match vesting_info.vesting_type {
-        _ => {
-            if let Some(duration) = vesting_info.vesting_duration {
-                calculate_linear_vesting(start_time, duration, total_amount, current_time)
-            } else {
-                U256::zero() // Default to no vesting if duration is None
+pub fn claim_vesting_amount(recipient: &Key) -> (VestingType, U256) {
+    let global_vesting_state = get_global_vesting_state();
+
+    let (vesting_type, amount) = match get_vesting_address(recipient) {
+        Some(VestingAddress::Type(vesting_type)) => {
+            let mut vesting_record = get_vesting_record(vesting_type);
+            if is_vesting_paused(&vesting_record, &global_vesting_state) {
+                revert(VestingError::VestingPaused);
             }
-        }
-    };
 
-    let is_fully_vested = vesting_info.vesting_duration.is_none() || vested_amount == total_amount;
-    let time_until_next_release = if is_fully_vested {
-        Duration::ZERO
-    } else {
-        vesting_info
-            .vesting_duration
-            .map_or(Duration::ZERO, |duration| {
-                calculate_time_until_next_release(start_time, duration, current_time)
-            })
-    };
+            (
+                vesting_type,
+                release_available_amount(&mut vesting_record, &global_vesting_state),
+            )
+        }
+        Some(VestingAddress::Grant) => {
+            let mut vesting_grant_record = get_vesting_grant_record(recipient)
+                .unwrap_or_revert_with(VestingError::MissingVestingGrant);
+            let funding_type = vesting_grant_record.vesting_grant.funding_type;
+            let funding_record = get_vesting_record(funding_type);
+            if is_vesting_paused(&funding_record, &global_vesting_state) {
+                revert(VestingError::VestingPaused);
+            }
 
-    let release_amount_per_period = if let Some(duration) = vesting_info.vesting_duration {
-        calculate_release_per_period(total_amount, duration)
-    } else {
-        U256::zero()
+            (
+                funding_type,
+                release_available_grant_amount(
+                    &mut vesting_grant_record,
+                    &funding_record,
+                    &global_vesting_state,
+                ),
+            )
+        }
+        Some(VestingAddress::Previous(_)) | None => revert(VestingError::InvalidBeneficiary),
     };
 
-    let released_amount = get_dictionary_value_from_key(
-        DICT_TRANSFERRED_AMOUNT,
-        &vesting_info.vesting_type.to_string(),
-    )
-    .unwrap_or_default();
-
-    let elapsed_time = current_time.saturating_sub(start_time);
-    let elapsed_time = Duration::seconds(elapsed_time as i64);
-
-    let elapsed_periods = if elapsed_time >= VESTING_PERIOD_IN_SECONDS {
-        U256::from(
-            elapsed_time
-                .whole_seconds()
-                .checked_div(VESTING_PERIOD_IN_SECONDS.whole_seconds())
-                .unwrap_or_default(),
-        )
-    } else {
-        U256::zero()
-    };
+    if amount.is_zero() {
+        revert(VestingError::NothingToClaim);
+    }
 
-    let expected_released_amount = if is_fully_vested {
-        // if elapsed_periods is above full vesting time we don't want to calculate expected_released_amount based on periods
-        total_amount
-    } else {
-        release_amount_per_period * elapsed_periods
-    };
+    (vesting_type, amount)
+}
 
-    let available_for_release_amount = if is_fully_vested {
-        expected_released_amount
-            .saturating_sub(released_amount)
-            .min(total_amount)
-    } else if expected_released_amount >= released_amount {
-        expected_released_amount.saturating_sub(released_amount)
-    } else {
-        U256::zero()
-    };
+#[cfg(feature = "contract-support")]
+fn release_available_grant_amount(
+    vesting_grant_record: &mut VestingGrantRecord,
+    funding_record: &VestingRecord,
+    global_vesting_state: &GlobalVestingState,
+) -> U256 {
+    let status = get_vesting_grant_status(
+        &vesting_grant_record.vesting_grant,
+        vesting_grant_record.transferred_amount,
+        funding_record,
+        global_vesting_state,
+    );
+    let amount = status.available_for_release_amount;
 
-    let total_to_release_amount = total_amount.saturating_sub(released_amount);
+    if !amount.is_zero() {
+        release_vesting_grant_amount(vesting_grant_record, status, amount);
+    }
 
-    VestingStatus::new(
-        vesting_info.vesting_type,
-        total_amount,
-        vested_amount,
-        is_fully_vested,
-        vesting_info.vesting_duration.unwrap_or(Duration::ZERO),
-        Duration::new(start_time as i64, 0),
-        time_until_next_release,
-        release_amount_per_period,
-        released_amount,
-        elapsed_periods,
-        available_for_release_amount,
-        total_to_release_amount,
-    )
+    amount
 }
 
+/// Records the amount due to a vesting type as released, for the contract to push it to the
+/// primary vesting address in custody mode. No amount is returned while the vesting type is
+/// paused.
 #[cfg(feature = "contract-support")]
-fn get_vesting_status_by_type(vesting_type: VestingType) -> VestingStatus {
-    let vesting_info = VESTING_INFO
-        .iter()
-        .find(|vesting_info| vesting_info.vesting_type == vesting_type)
-        .expect("Invalid vesting type");
-
-    let maybe_vesting_address_key =
-        get_dictionary_value_from_key(DICT_ADDRESSES, &vesting_info.vesting_type.to_string());
-    let start_time =
-        get_dictionary_value_from_key(DICT_START_TIME, &vesting_info.vesting_type.to_string())
-            .unwrap_or_default();
-    let total_amount =
-        get_dictionary_value_from_key(DICT_VESTING_AMOUNT, &vesting_info.vesting_type.to_string())
-            .unwrap_or_default();
+pub fn release_due_vesting_amount(
+    vesting_type: VestingType,
+    global_vesting_state: &GlobalVestingState,
+) -> (Key, Option<U256>) {
+    let mut vesting_record = get_vesting_record(vesting_type);
+    let recipient = vesting_record
+        .vesting_info
+        .maybe_vesting_address_key
+        .unwrap_or_revert_with(VestingError::MissingVestingAddress);
+
+    if is_vesting_paused(&vesting_record, global_vesting_state) {
+        return (recipient, None);
+    }
 
-    // Calculate the vesting status
-    get_vesting_status(
-        &VestingInfo {
-            vesting_type,
-            vesting_duration: vesting_info.vesting_duration,
-            maybe_vesting_address_key,
-        },
-        start_time,
-        total_amount,
+    (
+        recipient,
+        Some(release_available_amount(
+            &mut vesting_record,
+            global_vesting_state,
+        )),
     )
 }
 
+/// Same as `release_due_vesting_amount` for a grant, pushed to its beneficiary. Returns the
+/// funding vesting type, no amount is returned while it is paused.
 #[cfg(feature = "contract-support")]
-pub fn get_vesting_transfer(owner: Key, requested_amount: U256) -> bool {
-    let vesting_info = match get_vesting_info_by_key(&owner) {
-        Some(info) => info,
-        None => return true, // If owner is not a vesting address, allow transfer
-    };
-    let start_time =
-        get_dictionary_value_from_key(DICT_START_TIME, &vesting_info.vesting_type.to_string())
-            .unwrap_or_default();
-    let total_amount =
-        get_dictionary_value_from_key(DICT_VESTING_AMOUNT, &vesting_info.vesting_type.to_string())
-            .unwrap_or_default();
-
-    let status = get_vesting_status(&vesting_info, start_time, total_amount);
-
-    if requested_amount <= status.available_for_release_amount {
-        // Update transferred amount if all checks pass
-        let cumulative_transferred: U256 = get_dictionary_value_from_key(
-            DICT_TRANSFERRED_AMOUNT,
-            &vesting_info.vesting_type.to_string(),
-        )
-        .unwrap_or_default();
-        let new_transferred_amount = cumulative_transferred + requested_amount;
-        set_dictionary_value_for_key(
-            DICT_TRANSFERRED_AMOUNT,
-            &vesting_info.vesting_type.to_string(),
-            &new_transferred_amount,
-        );
-        update_vesting_status(vesting_info.vesting_type);
-        return true;
+pub fn release_due_vesting_grant_amount(
+    beneficiary: &Key,
+    global_vesting_state: &GlobalVestingState,
+) -> (VestingType, Option<U256>) {
+    let mut vesting_grant_record = get_vesting_grant_record(beneficiary)
+        .unwrap_or_revert_with(VestingError::MissingVestingGrant);
+    let funding_type = vesting_grant_record.vesting_grant.funding_type;
+    let funding_record = get_vesting_record(funding_type);
+
+    if is_vesting_paused(&funding_record, global_vesting_state) {
+        return (funding_type, None);
     }
-    status.is_fully_vested
+
+    (
+        funding_type,
+        Some(release_available_grant_amount(
+            &mut vesting_grant_record,
+            &funding_record,
+            global_vesting_state,
+        )),
+    )
 }
 
 #[cfg(feature = "contract-support")]
@@ -576,31 +2355,145 @@ pub fn get_current_balance_for_key(
     )
 }
 
+#[cfg(feature = "contract-support")]
+fn get_current_allowance_for_key(
+    contract_package_hash: ContractPackageHash,
+    owner: &Key,
+    spender: &Key,
+) -> U256 {
+    call_versioned_contract(
+        contract_package_hash,
+        None,
+        ENTRY_POINT_ALLOWANCE,
+        runtime_args! {ARG_OWNER => owner, ARG_SPENDER => spender },
+    )
+}
+
+/// Moves the balance of a previous vesting address to the new one, up to the amount it approved
+/// the vesting contract package for. Returns the moved amount and the balance left behind, an
+/// address whose key is lost can approve nothing and keeps its whole balance.
+#[cfg(feature = "contract-support")]
+pub fn move_approved_balance(owner: &Key, recipient: &Key) -> (U256, U256) {
+    let cowl_cep18_contract_package_hash = get_cowl_cep18_contract_package_hash();
+    let vesting_contract_package_key =
+        get_key(ARG_PACKAGE_HASH).unwrap_or_revert_with(VestingError::MissingPackageHash);
+
+    let balance = get_current_balance_for_key(cowl_cep18_contract_package_hash, owner);
+    let allowance = get_current_allowance_for_key(
+        cowl_cep18_contract_package_hash,
+        owner,
+        &vesting_contract_package_key,
+    );
+    let moved_amount = balance.min(allowance);
+
+    if !moved_amount.is_zero() {
+        call_versioned_contract::<()>(
+            cowl_cep18_contract_package_hash,
+            None,
+            ENTRY_POINT_TRANSFER_FROM,
+            runtime_args! {
+                ARG_OWNER => *owner,
+                ARG_RECIPIENT => *recipient,
+                ARG_AMOUNT => moved_amount
+            },
+        );
+    }
+
+    (moved_amount, balance - moved_amount)
+}
+
 #[cfg(feature = "contract-support")]
 fn calculate_time_until_next_release(
     start_time: u64,
     duration: Duration,
+    vesting_period: Duration,
+    period_mode: PeriodMode,
     current_time: u64,
 ) -> Duration {
-    let elapsed = current_time.saturating_sub(start_time);
-    let total_duration = duration.whole_seconds() as u64;
-    let vesting_period = VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64;
+    let vesting_end = calculate_vesting_end(start_time, duration, vesting_period, period_mode);
 
-    if elapsed >= total_duration {
+    if current_time >= vesting_end {
         return Duration::ZERO;
     }
 
-    // Perform modulo on raw seconds
-    let time_in_period = elapsed % vesting_period;
-    let remaining_time_secs = vesting_period.saturating_sub(time_in_period);
+    let elapsed_periods =
+        calculate_elapsed_periods(start_time, vesting_period, period_mode, current_time);
+    let next_release = calculate_period_end(
+        start_time,
+        vesting_period,
+        period_mode,
+        elapsed_periods.saturating_add(1),
+    )
+    .min(vesting_end);
+
+    Duration::seconds(next_release.saturating_sub(current_time) as i64)
+}
+
+/// Timestamp at which the given number of release periods have elapsed since start time
+#[cfg(feature = "contract-support")]
+fn calculate_period_end(
+    start_time: u64,
+    vesting_period: Duration,
+    period_mode: PeriodMode,
+    periods: u64,
+) -> u64 {
+    match period_mode {
+        PeriodMode::Fixed => {
+            start_time.saturating_add(periods.saturating_mul(vesting_period.whole_seconds() as u64))
+        }
+        PeriodMode::Calendar => add_calendar_months(
+            start_time,
+            periods.saturating_mul(calendar_months_in_period(vesting_period)),
+        )
+        .unwrap_or(u64::MAX),
+    }
+}
+
+#[cfg(feature = "contract-support")]
+fn calculate_elapsed_periods(
+    start_time: u64,
+    vesting_period: Duration,
+    period_mode: PeriodMode,
+    current_time: u64,
+) -> u64 {
+    match period_mode {
+        PeriodMode::Fixed => current_time
+            .saturating_sub(start_time)
+            .checked_div(vesting_period.whole_seconds() as u64)
+            .unwrap_or_default(),
+        PeriodMode::Calendar => elapsed_calendar_months(start_time, current_time)
+            .checked_div(calendar_months_in_period(vesting_period))
+            .unwrap_or_default(),
+    }
+}
 
-    Duration::seconds(remaining_time_secs as i64)
+/// In calendar mode the schedule ends once all its periods have elapsed on the calendar
+#[cfg(feature = "contract-support")]
+fn calculate_vesting_end(
+    start_time: u64,
+    duration: Duration,
+    vesting_period: Duration,
+    period_mode: PeriodMode,
+) -> u64 {
+    match period_mode {
+        PeriodMode::Fixed => start_time.saturating_add(duration.whole_seconds() as u64),
+        PeriodMode::Calendar => {
+            let total_periods = (duration.whole_seconds() as u64)
+                .checked_div(vesting_period.whole_seconds() as u64)
+                .unwrap_or_default();
+            calculate_period_end(start_time, vesting_period, period_mode, total_periods)
+        }
+    }
 }
 
 #[cfg(feature = "contract-support")]
-fn calculate_release_per_period(total_amount: U256, duration: Duration) -> U256 {
+fn calculate_release_per_period(
+    total_amount: U256,
+    duration: Duration,
+    vesting_period: Duration,
+) -> U256 {
     let periods = (duration.whole_seconds() as u64)
-        .checked_div(VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64)
+        .checked_div(vesting_period.whole_seconds() as u64)
         .unwrap_or(0);
 
     if periods == 0 || total_amount.is_zero() {
@@ -612,29 +2505,49 @@ fn calculate_release_per_period(total_amount: U256, duration: Duration) -> U256
         .unwrap_or(U256::zero())
 }
 
+#[cfg(feature = "contract-support")]
+fn calculate_share_amount(total_amount: U256, share: u16) -> U256 {
+    total_amount
+        .checked_mul(U256::from(share))
+        .and_then(|amount| amount.checked_div(U256::from(BASIS_POINTS_DENOMINATOR)))
+        .unwrap_or_revert_with(VestingError::Overflow)
+        .min(total_amount)
+}
+
+#[cfg(feature = "contract-support")]
+fn calculate_cliff_end(start_time: u64, cliff_duration: Option<Duration>) -> u64 {
+    cliff_duration.map_or(start_time, |cliff| {
+        start_time.saturating_add(cliff.whole_seconds() as u64)
+    })
+}
+
 #[cfg(feature = "contract-support")]
 fn calculate_linear_vesting(
     start_time: u64,
     duration: Duration,
+    cliff_duration: Option<Duration>,
+    vesting_period: Duration,
+    period_mode: PeriodMode,
     total_amount: U256,
     current_time: u64,
 ) -> U256 {
-    let elapsed_time = current_time.saturating_sub(start_time);
-    let total_duration = duration.whole_seconds() as u64;
-    let vesting_period = VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64;
-
-    if elapsed_time == 0_u64 {
-        return U256::zero();
-    }
-
-    if elapsed_time >= total_duration {
-        return total_amount;
+    if let Some(vested_amount) = calculate_vesting_bounds(
+        start_time,
+        duration,
+        cliff_duration,
+        vesting_period,
+        period_mode,
+        total_amount,
+        current_time,
+    ) {
+        return vested_amount;
     }
 
-    let total_periods = total_duration
-        .checked_div(vesting_period)
+    let total_periods = (duration.whole_seconds() as u64)
+        .checked_div(vesting_period.whole_seconds() as u64)
         .unwrap_or_default();
-    let elapsed_periods = elapsed_time.checked_div(vesting_period).unwrap_or_default();
+    let elapsed_periods =
+        calculate_elapsed_periods(start_time, vesting_period, period_mode, current_time);
 
     let amount_per_period = total_amount
         .checked_div(U256::from(total_periods))
@@ -644,34 +2557,281 @@ fn calculate_linear_vesting(
 }
 
 #[cfg(feature = "contract-support")]
-pub fn calculate_vesting_allocations(initial_supply: U256) -> Vec<VestingAllocation> {
-    VESTING_PERCENTAGES
+fn calculate_continuous_vesting(
+    start_time: u64,
+    duration: Duration,
+    cliff_duration: Option<Duration>,
+    vesting_period: Duration,
+    period_mode: PeriodMode,
+    total_amount: U256,
+    current_time: u64,
+) -> U256 {
+    if let Some(vested_amount) = calculate_vesting_bounds(
+        start_time,
+        duration,
+        cliff_duration,
+        vesting_period,
+        period_mode,
+        total_amount,
+        current_time,
+    ) {
+        return vested_amount;
+    }
+
+    let vesting_end = calculate_vesting_end(start_time, duration, vesting_period, period_mode);
+    let total_time = vesting_end.saturating_sub(start_time);
+    let elapsed_time = current_time.saturating_sub(start_time);
+
+    total_amount
+        .checked_mul(U256::from(elapsed_time))
+        .unwrap_or_revert_with(VestingError::Overflow)
+        .checked_div(U256::from(total_time))
+        .unwrap_or_default()
+        .min(total_amount)
+}
+
+#[cfg(feature = "contract-support")]
+fn calculate_back_loaded_vesting(
+    start_time: u64,
+    duration: Duration,
+    cliff_duration: Option<Duration>,
+    vesting_period: Duration,
+    period_mode: PeriodMode,
+    total_amount: U256,
+    current_time: u64,
+) -> U256 {
+    if let Some(vested_amount) = calculate_vesting_bounds(
+        start_time,
+        duration,
+        cliff_duration,
+        vesting_period,
+        period_mode,
+        total_amount,
+        current_time,
+    ) {
+        return vested_amount;
+    }
+
+    let total_periods = U256::from(
+        (duration.whole_seconds() as u64)
+            .checked_div(vesting_period.whole_seconds() as u64)
+            .unwrap_or_default(),
+    );
+    let elapsed_periods = U256::from(calculate_elapsed_periods(
+        start_time,
+        vesting_period,
+        period_mode,
+        current_time,
+    ));
+
+    // Quadratic curve: vested share is (elapsed_periods / total_periods)^2
+    total_amount
+        .checked_mul(elapsed_periods * elapsed_periods)
+        .unwrap_or_revert_with(VestingError::Overflow)
+        .checked_div(total_periods * total_periods)
+        .unwrap_or_default()
+        .min(total_amount)
+}
+
+#[cfg(feature = "contract-support")]
+fn calculate_tranche_vesting(
+    start_time: u64,
+    cliff_duration: Option<Duration>,
+    vesting_tranches: &[VestingTranche],
+    total_amount: U256,
+    current_time: u64,
+) -> U256 {
+    if current_time < calculate_cliff_end(start_time, cliff_duration) {
+        return U256::zero();
+    }
+
+    let reached_tranches = calculate_reached_tranches(start_time, vesting_tranches, current_time);
+
+    vesting_tranches[..reached_tranches]
+        .last()
+        .map_or(U256::zero(), |tranche| {
+            calculate_share_amount(total_amount, tranche.cumulative_share)
+        })
+}
+
+#[cfg(feature = "contract-support")]
+fn calculate_milestone_vesting(
+    start_time: u64,
+    cliff_duration: Option<Duration>,
+    vesting_milestones: &[VestingMilestone],
+    total_amount: U256,
+    current_time: u64,
+) -> U256 {
+    if current_time < calculate_cliff_end(start_time, cliff_duration) {
+        return U256::zero();
+    }
+
+    let released_share: u16 = vesting_milestones
+        .iter()
+        .filter(|milestone| milestone.released_at.is_some())
+        .map(|milestone| milestone.share)
+        .sum();
+
+    calculate_share_amount(total_amount, released_share)
+}
+
+/// Marks a milestone of a milestone schedule as reached, returning its share
+#[cfg(feature = "contract-support")]
+pub fn release_vesting_milestone(vesting_type: VestingType, milestone_name: &str) -> u16 {
+    let mut vesting_record = get_vesting_record(vesting_type);
+
+    if vesting_record.vesting_info.release_curve != ReleaseCurve::Milestones {
+        revert(VestingError::NotMilestoneSchedule);
+    }
+
+    let vesting_milestone = vesting_record
+        .vesting_milestones
+        .iter_mut()
+        .find(|milestone| milestone.name == milestone_name)
+        .unwrap_or_revert_with(VestingError::InvalidMilestoneName);
+
+    if vesting_milestone.released_at.is_some() {
+        revert(VestingError::MilestoneAlreadyReleased);
+    }
+
+    let current_time_in_ms: u64 = get_blocktime().into();
+    vesting_milestone.released_at = Some(current_time_in_ms.checked_div(1000).unwrap_or_default());
+    let share = vesting_milestone.share;
+
+    refresh_vesting_status(&mut vesting_record);
+    set_vesting_record(&mut vesting_record);
+
+    share
+}
+
+/// Number of tranches whose unlock date has passed, tranches are sorted by unlock date
+#[cfg(feature = "contract-support")]
+fn calculate_reached_tranches(
+    start_time: u64,
+    vesting_tranches: &[VestingTranche],
+    current_time: u64,
+) -> usize {
+    vesting_tranches
         .iter()
-        .filter_map(|(vesting_type, percentage)| {
-            // Find the corresponding `VestingInfo` for each vesting type
-            get_vesting_info()
-                .iter()
-                .find(|info| info.vesting_type == *vesting_type)
-                .map(|info| {
-                    let vesting_address_key = info
-                        .maybe_vesting_address_key
-                        .unwrap_or_revert_with(VestingError::MissingKey);
-                    (vesting_type, vesting_address_key, percentage)
-                })
+        .take_while(|tranche| calculate_tranche_release_time(start_time, tranche) <= current_time)
+        .count()
+}
+
+#[cfg(feature = "contract-support")]
+fn calculate_tranche_release_time(start_time: u64, vesting_tranche: &VestingTranche) -> u64 {
+    start_time.saturating_add(vesting_tranche.release_offset.whole_seconds() as u64)
+}
+
+#[cfg(feature = "contract-support")]
+fn calculate_time_until_next_tranche(
+    start_time: u64,
+    vesting_tranches: &[VestingTranche],
+    current_time: u64,
+) -> Duration {
+    let reached_tranches = calculate_reached_tranches(start_time, vesting_tranches, current_time);
+
+    vesting_tranches
+        .get(reached_tranches)
+        .map_or(Duration::ZERO, |tranche| {
+            let release_time = calculate_tranche_release_time(start_time, tranche);
+            Duration::seconds(release_time.saturating_sub(current_time) as i64)
         })
-        .map(|(vesting_type, vesting_address_key, percentage)| {
+}
+
+#[cfg(feature = "contract-support")]
+fn calculate_next_tranche_amount(
+    start_time: u64,
+    vesting_tranches: &[VestingTranche],
+    total_amount: U256,
+    current_time: u64,
+) -> U256 {
+    let reached_tranches = calculate_reached_tranches(start_time, vesting_tranches, current_time);
+    let reached_share = vesting_tranches[..reached_tranches]
+        .last()
+        .map_or(0, |tranche| tranche.cumulative_share);
+
+    vesting_tranches
+        .get(reached_tranches)
+        .map_or(U256::zero(), |tranche| {
+            calculate_share_amount(total_amount, tranche.cumulative_share)
+                .saturating_sub(calculate_share_amount(total_amount, reached_share))
+        })
+}
+
+/// Vested amount shared by all release curves outside of the curve itself: nothing before
+/// the first second of vesting or the end of the cliff, everything once the vesting has ended
+#[cfg(feature = "contract-support")]
+fn calculate_vesting_bounds(
+    start_time: u64,
+    duration: Duration,
+    cliff_duration: Option<Duration>,
+    vesting_period: Duration,
+    period_mode: PeriodMode,
+    total_amount: U256,
+    current_time: u64,
+) -> Option<U256> {
+    let elapsed_time = current_time.saturating_sub(start_time);
+
+    if elapsed_time == 0_u64 || current_time < calculate_cliff_end(start_time, cliff_duration) {
+        return Some(U256::zero());
+    }
+
+    if current_time >= calculate_vesting_end(start_time, duration, vesting_period, period_mode) {
+        return Some(total_amount);
+    }
+
+    None
+}
+
+/// Splits the supply by basis points, flooring each share and assigning the rounding
+/// remainder to `VESTING_DUST_RECIPIENT` so the whole supply is always distributed
+#[cfg(feature = "contract-support")]
+pub fn calculate_vesting_allocations(
+    initial_supply: U256,
+    vesting_configs: &[VestingConfig],
+) -> Vec<VestingAllocation> {
+    let mut allocations: Vec<VestingAllocation> = vesting_configs
+        .iter()
+        .map(|config| {
+            let vesting_address_key = get_vesting_info_by_type(&config.vesting_type)
+                .and_then(|info| info.maybe_vesting_address_key)
+                .unwrap_or_revert_with(VestingError::MissingKey);
+
             let vesting_amount = initial_supply
-                .checked_mul(U256::from(*percentage))
+                .checked_mul(U256::from(config.vesting_share))
                 .unwrap_or_revert_with(VestingError::Overflow)
-                .checked_div(U256::from(100))
+                .checked_div(U256::from(BASIS_POINTS_DENOMINATOR))
                 .unwrap_or_revert_with(VestingError::Overflow);
 
             // Create the VestingAllocation with the required fields
             VestingAllocation {
-                vesting_type: *vesting_type,
+                vesting_type: config.vesting_type,
                 vesting_address_key,
                 vesting_amount,
             }
         })
-        .collect()
+        .collect();
+
+    let allocated_amount = allocations
+        .iter()
+        .try_fold(U256::zero(), |total, allocation| {
+            total.checked_add(allocation.vesting_amount)
+        })
+        .unwrap_or_revert_with(VestingError::Overflow);
+
+    let dust_amount = initial_supply
+        .checked_sub(allocated_amount)
+        .unwrap_or_revert_with(VestingError::Overflow);
+
+    if let Some(dust_allocation) = allocations
+        .iter_mut()
+        .find(|allocation| allocation.vesting_type == VESTING_DUST_RECIPIENT)
+    {
+        dust_allocation.vesting_amount = dust_allocation
+            .vesting_amount
+            .checked_add(dust_amount)
+            .unwrap_or_revert_with(VestingError::Overflow);
+    }
+
+    allocations
 }
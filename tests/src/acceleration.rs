@@ -0,0 +1,392 @@
+use crate::utility::{
+    constants::{ACCOUNT_USER_1, ACCOUNT_USER_2, VESTING_CONTRACT_PACKAGE_HASH_KEY_NAME},
+    installer_request_builders::{
+        cowl_cep18_token_increase_allowance, cowl_cep18_token_transfer, cowl_vesting_accelerate,
+        cowl_vesting_create_grant, cowl_vesting_vesting_status, setup, setup_with_args,
+        TestContext,
+    },
+    support::{assert_expected_error, get_account_for_vesting, get_event, get_vesting_record},
+};
+use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use cowl_vesting::{
+    constants::{
+        ARG_ACCELERATION_TIME, ARG_VESTING_ALLOCATIONS, DURATION_COMMUNITY_VESTING,
+        ENTRY_POINT_ACCELERATE, MONTH_IN_SECONDS, YEAR_IN_SECONDS,
+    },
+    enums::{PeriodMode, ReleaseCurve, VestingType},
+    error::VestingError,
+    events::VestingAcceleration,
+    vesting::{get_default_vesting_configs, VestingGrant},
+};
+use time::Duration;
+
+#[test]
+fn should_allow_full_vesting_transfer_from_acceleration_time() {
+    let vesting_type = VestingType::Community;
+    let acceleration_time = 1_000;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let vesting_account = *test_accounts
+        .get(&get_account_for_vesting(vesting_type))
+        .unwrap();
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    cowl_vesting_accelerate(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        Some(vesting_type),
+        Some(acceleration_time),
+        Some(acceleration_time),
+    )
+    .expect_success()
+    .commit();
+
+    let expected_event = VestingAcceleration::new(
+        Key::from(*DEFAULT_ACCOUNT_ADDR),
+        Some(vesting_type.to_string()),
+        acceleration_time,
+    );
+    let actual_event: VestingAcceleration =
+        get_event(&builder, &cowl_vesting_contract_hash.into(), 0);
+    assert_eq!(actual_event, expected_event);
+
+    let vesting_amount = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_amount;
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &vesting_account,
+        vesting_amount,
+        &account_user_1,
+        Some(acceleration_time - 1),
+    )
+    .expect_failure();
+
+    cowl_vesting_vesting_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Some(acceleration_time),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_record = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    );
+    assert_eq!(vesting_record.acceleration_time, Some(acceleration_time));
+    assert!(vesting_record.vesting_status.is_fully_vested);
+    assert_eq!(vesting_record.vesting_status.vested_amount, vesting_amount);
+    assert_eq!(
+        vesting_record.vesting_status.available_for_release_amount,
+        vesting_amount
+    );
+    // The schedule is kept as it was
+    assert_eq!(
+        vesting_record.vesting_status.vesting_duration,
+        DURATION_COMMUNITY_VESTING.unwrap()
+    );
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &vesting_account,
+        vesting_amount,
+        &account_user_1,
+        Some(acceleration_time),
+    )
+    .expect_success()
+    .commit();
+}
+
+#[test]
+fn should_accelerate_all_vesting_types_once_by_admin_only() {
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    cowl_vesting_accelerate(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        None,
+        None,
+        None,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InsufficientRights as u16,
+        "only admins can accelerate",
+    );
+
+    cowl_vesting_accelerate(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        None,
+        None,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    for vesting_type in [VestingType::Community, VestingType::Treasury] {
+        let vesting_record = get_vesting_record(
+            &builder,
+            &Key::from(cowl_vesting_contract_hash),
+            vesting_type,
+        );
+        assert!(vesting_record.vesting_status.is_fully_vested);
+    }
+
+    cowl_vesting_accelerate(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        Some(VestingType::Treasury),
+        None,
+        None,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::VestingAlreadyAccelerated as u16,
+        "vesting types are accelerated once",
+    );
+}
+
+#[test]
+fn should_accelerate_grants_with_all_vesting_types() {
+    let funding_type = VestingType::Treasury;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let funding_account = *test_accounts
+        .get(&get_account_for_vesting(funding_type))
+        .unwrap();
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+
+    let cowl_vesting_contract_package_key = *builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(VESTING_CONTRACT_PACKAGE_HASH_KEY_NAME)
+        .expect("should have package hash");
+
+    let grant_amount = U256::from(12_000_000_000_u64);
+
+    cowl_cep18_token_increase_allowance(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &funding_account,
+        cowl_vesting_contract_package_key,
+        grant_amount,
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_create_grant(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        VestingGrant {
+            beneficiary: Key::from(account_user_1),
+            funding_type,
+            amount: grant_amount,
+            start_time: Some(MONTH_IN_SECONDS),
+            vesting_duration: Some(Duration::seconds(YEAR_IN_SECONDS as i64)),
+            cliff_duration: None,
+            vesting_period: Duration::seconds(MONTH_IN_SECONDS as i64),
+            period_mode: PeriodMode::Fixed,
+            release_curve: ReleaseCurve::Stepwise,
+            initial_unlock_share: 0,
+            tranches: Vec::new(),
+            is_revocable: false,
+            revoke_time: None,
+        },
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_accelerate(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        None,
+        Some(MONTH_IN_SECONDS),
+        Some(MONTH_IN_SECONDS),
+    )
+    .expect_success()
+    .commit();
+
+    // The acceleration of all vesting types is kept apart from the acceleration of each type
+    let funding_record = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        funding_type,
+    );
+    assert_eq!(funding_record.acceleration_time, None);
+    assert!(funding_record.vesting_status.is_fully_vested);
+
+    // The grant follows its funding vesting type, nothing of it would be vested otherwise
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &account_user_1,
+        grant_amount,
+        &account_user_2,
+        Some(MONTH_IN_SECONDS),
+    )
+    .expect_success()
+    .commit();
+}
+
+#[test]
+fn should_not_accelerate_with_invalid_acceleration_time() {
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ..
+        },
+    ) = setup();
+
+    let accelerate_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        cowl_vesting_contract_hash,
+        ENTRY_POINT_ACCELERATE,
+        runtime_args! {
+            ARG_ACCELERATION_TIME => "soon",
+        },
+    )
+    .build();
+    builder.exec(accelerate_request).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InvalidAccelerationTime as u16,
+        "acceleration time must be a timestamp",
+    );
+}
+
+#[test]
+fn should_not_accelerate_in_the_future_nor_before_start_time() {
+    let vesting_type = VestingType::Liquidity;
+    let start_time = YEAR_IN_SECONDS;
+
+    let mut vesting_configs = get_default_vesting_configs();
+    for vesting_config in vesting_configs.iter_mut() {
+        if vesting_config.vesting_type == vesting_type {
+            vesting_config.start_time = Some(start_time);
+        }
+    }
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_VESTING_ALLOCATIONS => vesting_configs,
+        },
+        None,
+    );
+
+    // A future acceleration time could never be brought forward
+    for maybe_vesting_type in [Some(VestingType::Community), None] {
+        cowl_vesting_accelerate(
+            &mut builder,
+            &cowl_vesting_contract_hash,
+            &DEFAULT_ACCOUNT_ADDR,
+            maybe_vesting_type,
+            Some(u64::MAX),
+            Some(MONTH_IN_SECONDS),
+        )
+        .expect_failure();
+
+        let error = builder.get_error().expect("must have error");
+        assert_expected_error(
+            error,
+            VestingError::InvalidAccelerationTime as u16,
+            "acceleration time cannot be in the future",
+        );
+    }
+
+    cowl_vesting_accelerate(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        Some(vesting_type),
+        Some(start_time - 1),
+        Some(start_time),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InvalidAccelerationTime as u16,
+        "acceleration time cannot be before start time",
+    );
+
+    cowl_vesting_accelerate(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        Some(vesting_type),
+        Some(start_time),
+        Some(start_time),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_record = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    );
+    assert_eq!(vesting_record.acceleration_time, Some(start_time));
+}
@@ -0,0 +1,429 @@
+use crate::utility::{
+    constants::{ACCOUNT_USER_1, ACCOUNT_USER_2},
+    installer_request_builders::{
+        cowl_cep18_token_transfer, cowl_vesting_change_security, cowl_vesting_claim,
+        cowl_vesting_create_grant, cowl_vesting_pause, cowl_vesting_release_due, setup,
+        setup_with_args, SecurityLists, TestContext,
+    },
+    support::{
+        assert_expected_error, get_account_for_vesting, get_event, get_vesting_grant_record,
+        get_vesting_record,
+    },
+};
+use casper_engine_test_support::{InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use cowl_vesting::{
+    constants::{ARG_EVENTS_MODE, ARG_VESTING_MODE, MONTH_IN_SECONDS, YEAR_IN_SECONDS},
+    enums::{EventsMode, PeriodMode, ReleaseCurve, VestingMode, VestingType},
+    error::VestingError,
+    events::{VestingRelease, VestingReleaseSkip},
+    vesting::VestingGrant,
+};
+use time::Duration;
+
+fn setup_with_custody() -> (InMemoryWasmTestBuilder, TestContext) {
+    setup_with_args(
+        runtime_args! {
+            ARG_EVENTS_MODE => EventsMode::CES as u8,
+            ARG_VESTING_MODE => VestingMode::Custody as u8,
+        },
+        None,
+    )
+}
+
+#[test]
+fn should_claim_available_amount_held_in_custody() {
+    // Liquidity has no vesting duration and is fully available right after start time
+    let vesting_type = VestingType::Liquidity;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_custody();
+
+    let vesting_account = *test_accounts
+        .get(&get_account_for_vesting(vesting_type))
+        .unwrap();
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    let vesting_amount: U256 = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_amount;
+    assert!(vesting_amount > U256::zero());
+
+    // Nothing has been transferred to the vesting address yet
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &vesting_account,
+        U256::one(),
+        &account_user_1,
+        None,
+    )
+    .expect_failure();
+
+    cowl_vesting_claim(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &vesting_account,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    let transferred_amount: U256 = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .transferred_amount;
+    assert_eq!(transferred_amount, vesting_amount);
+
+    let expected_event = VestingRelease::new(
+        Key::from(vesting_account),
+        vesting_type.to_string(),
+        vesting_amount,
+    );
+    let actual_event: VestingRelease = get_event(&builder, &cowl_vesting_contract_hash.into(), 0);
+    assert_eq!(actual_event, expected_event);
+
+    // Claimed tokens are released and move without a filter
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &vesting_account,
+        vesting_amount,
+        &account_user_1,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_claim(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &vesting_account,
+        None,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::NothingToClaim as u16,
+        "should not claim twice the same available amount",
+    );
+}
+
+#[test]
+fn should_not_claim_without_vesting_schedule() {
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_custody();
+
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+
+    cowl_vesting_claim(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_2,
+        None,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InvalidBeneficiary as u16,
+        "only vesting addresses and beneficiaries can claim",
+    );
+}
+
+#[test]
+fn should_not_claim_when_allocations_are_transferred() {
+    let vesting_type = VestingType::Liquidity;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let vesting_account = *test_accounts
+        .get(&get_account_for_vesting(vesting_type))
+        .unwrap();
+
+    cowl_vesting_claim(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &vesting_account,
+        None,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InvalidVestingMode as u16,
+        "claim is only available in custody mode",
+    );
+}
+
+#[test]
+fn should_push_due_amounts_of_a_batch_of_vesting_types() {
+    let vesting_type = VestingType::Liquidity;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_custody();
+
+    let vesting_account = *test_accounts
+        .get(&get_account_for_vesting(vesting_type))
+        .unwrap();
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+
+    let vesting_amount: U256 = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_amount;
+
+    cowl_vesting_release_due(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_2,
+        None,
+        None,
+        None,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InsufficientRights as u16,
+        "only keepers and admins can push releases",
+    );
+
+    cowl_vesting_change_security(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        SecurityLists {
+            keeper_list: Some(vec![Key::Account(account_user_2)]),
+            ..Default::default()
+        },
+    )
+    .expect_success()
+    .commit();
+
+    // Keepers push releases to the vesting addresses
+    cowl_vesting_release_due(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_2,
+        Some(vec![vesting_type, VestingType::Community]),
+        None,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    let transferred_amount: U256 = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .transferred_amount;
+    assert_eq!(transferred_amount, vesting_amount);
+
+    // Types outside the batch are left untouched
+    let transferred_amount: U256 = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        VestingType::Treasury,
+    )
+    .transferred_amount;
+    assert_eq!(transferred_amount, U256::zero());
+
+    let expected_event = VestingRelease::new(
+        Key::from(vesting_account),
+        vesting_type.to_string(),
+        vesting_amount,
+    );
+    // The first event is the keeper badge change
+    let actual_event: VestingRelease = get_event(&builder, &cowl_vesting_contract_hash.into(), 1);
+    assert_eq!(actual_event, expected_event);
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &vesting_account,
+        vesting_amount,
+        &account_user_1,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    // Types with nothing due are skipped
+    cowl_vesting_release_due(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_2,
+        None,
+        None,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    let transferred_amount: U256 = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .transferred_amount;
+    assert_eq!(transferred_amount, vesting_amount);
+}
+
+#[test]
+fn should_push_due_amounts_of_listed_grants_and_report_paused_schedules() {
+    let funding_type = VestingType::Treasury;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_custody();
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let beneficiary = Key::from(account_user_1);
+    let grant_amount = U256::from(12_000_000_000_u64);
+
+    cowl_vesting_create_grant(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        VestingGrant {
+            beneficiary,
+            funding_type,
+            amount: grant_amount,
+            start_time: Some(MONTH_IN_SECONDS),
+            vesting_duration: Some(Duration::seconds(YEAR_IN_SECONDS as i64)),
+            cliff_duration: None,
+            vesting_period: Duration::seconds(MONTH_IN_SECONDS as i64),
+            period_mode: PeriodMode::Fixed,
+            release_curve: ReleaseCurve::Stepwise,
+            initial_unlock_share: 0,
+            tranches: Vec::new(),
+            is_revocable: false,
+            revoke_time: None,
+        },
+    )
+    .expect_success()
+    .commit();
+
+    // Grants are pushed when listed, an empty batch of vesting types leaves them out
+    cowl_vesting_release_due(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        Some(vec![]),
+        Some(vec![beneficiary]),
+        Some(2 * MONTH_IN_SECONDS),
+    )
+    .expect_success()
+    .commit();
+
+    let release_amount_per_period = grant_amount / U256::from(12);
+
+    let transferred_amount = get_vesting_grant_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        &beneficiary,
+    )
+    .transferred_amount;
+    assert_eq!(transferred_amount, release_amount_per_period);
+
+    // The first event is the grant creation
+    let expected_event = VestingRelease::new(
+        beneficiary,
+        funding_type.to_string(),
+        release_amount_per_period,
+    );
+    let actual_event: VestingRelease = get_event(&builder, &cowl_vesting_contract_hash.into(), 1);
+    assert_eq!(actual_event, expected_event);
+
+    cowl_vesting_pause(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        Some(funding_type),
+    )
+    .expect_success()
+    .commit();
+
+    // Paused schedules are reported and left for a later run
+    cowl_vesting_release_due(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        Some(vec![funding_type]),
+        Some(vec![beneficiary]),
+        Some(3 * MONTH_IN_SECONDS),
+    )
+    .expect_success()
+    .commit();
+
+    let transferred_amount = get_vesting_grant_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        &beneficiary,
+    )
+    .transferred_amount;
+    assert_eq!(transferred_amount, release_amount_per_period);
+
+    let vesting_address = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        funding_type,
+    )
+    .vesting_info
+    .maybe_vesting_address_key
+    .unwrap();
+
+    for (index, recipient) in [(3, vesting_address), (4, beneficiary)] {
+        let expected_event = VestingReleaseSkip::new(recipient, funding_type.to_string());
+        let actual_event: VestingReleaseSkip =
+            get_event(&builder, &cowl_vesting_contract_hash.into(), index);
+        assert_eq!(actual_event, expected_event);
+    }
+}
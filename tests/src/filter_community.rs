@@ -3,22 +3,24 @@ use crate::{
     utility::{
         constants::ACCOUNT_USER_1,
         installer_request_builders::{
-            cowl_cep18_token_transfer, cowl_vesting_vesting_status, setup, TestContext,
+            cowl_cep18_token_transfer, cowl_vesting_vesting_status, setup, setup_with_args,
+            TestContext,
         },
-        support::{get_account_for_vesting, get_dictionary_value_from_key},
+        support::{get_account_for_vesting, get_vesting_dictionary_accesses, get_vesting_record},
     },
 };
 use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
-use casper_types::{Key, U256};
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
 use cowl_vesting::{
     constants::{
-        DICT_TRANSFERRED_AMOUNT, DICT_VESTING_STATUS, DURATION_COMMUNITY_VESTING,
-        VESTING_PERIOD_IN_SECONDS,
+        ARG_VESTING_ALLOCATIONS, BASIS_POINTS_DENOMINATOR, DICT_VESTING_ADDRESSES,
+        DICT_VESTING_RECORDS, DURATION_COMMUNITY_VESTING, PERIOD_COMMUNITY_VESTING,
     },
     enums::VestingType,
     events::CheckTransfer,
-    vesting::VestingStatus,
+    vesting::{get_default_vesting_configs, get_vesting_address_dictionary_key, VestingStatus},
 };
+use std::collections::BTreeMap;
 
 #[test]
 fn should_not_allow_transfer_for_non_vesting_address_at_zero_time() {
@@ -51,12 +53,12 @@ fn should_not_allow_transfer_for_non_vesting_address_at_zero_time() {
     )
     .expect_failure();
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert_eq!(vesting_status.vested_amount, U256::zero());
     assert_eq!(vesting_status.vesting_type, vesting_type);
@@ -94,25 +96,25 @@ fn should_allow_transfer_for_non_vesting_address_at_time_one_period() {
         &sender,
         transfer_amount,
         &account_user_1,
-        Some(VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64),
+        Some(PERIOD_COMMUNITY_VESTING.whole_seconds() as u64),
     )
     .expect_success()
     .commit();
 
-    let actual_transfered_amount: U256 = get_dictionary_value_from_key(
+    let actual_transfered_amount: U256 = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_TRANSFERRED_AMOUNT,
-        &vesting_type.to_string(),
-    );
+        vesting_type,
+    )
+    .transferred_amount;
     assert_eq!(actual_transfered_amount, transfer_amount);
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(vesting_status.vested_amount > U256::zero());
     assert_eq!(vesting_status.vesting_type, vesting_type);
@@ -156,12 +158,12 @@ fn should_not_allow_transfer_for_more_than_vested_amount_at_time_one_period() {
         .get(&get_account_for_vesting(vesting_type))
         .unwrap();
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     let transfer_amount = vesting_status.release_amount_per_period + 1;
 
@@ -171,7 +173,7 @@ fn should_not_allow_transfer_for_more_than_vested_amount_at_time_one_period() {
         &sender,
         transfer_amount,
         &account_user_1,
-        Some(VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64),
+        Some(PERIOD_COMMUNITY_VESTING.whole_seconds() as u64),
     )
     .expect_failure()
     .commit();
@@ -181,17 +183,17 @@ fn should_not_allow_transfer_for_more_than_vested_amount_at_time_one_period() {
         &cowl_vesting_contract_hash,
         &DEFAULT_ACCOUNT_ADDR,
         vesting_type,
-        Some(VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64),
+        Some(PERIOD_COMMUNITY_VESTING.whole_seconds() as u64),
     )
     .expect_success()
     .commit();
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
     assert_eq!(vesting_status.vested_amount, transfer_amount - U256::one());
     assert_eq!(vesting_status.released_amount, U256::zero());
     assert_eq!(
@@ -225,12 +227,12 @@ fn should_allow_full_transfer_for_non_vesting_address_at_time_one_period() {
         .get(&get_account_for_vesting(vesting_type))
         .unwrap();
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     let transfer_amount = vesting_status.release_amount_per_period;
 
@@ -240,25 +242,25 @@ fn should_allow_full_transfer_for_non_vesting_address_at_time_one_period() {
         &sender,
         transfer_amount,
         &account_user_1,
-        Some(VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64),
+        Some(PERIOD_COMMUNITY_VESTING.whole_seconds() as u64),
     )
     .expect_success()
     .commit();
 
-    let actual_transfered_amount: U256 = get_dictionary_value_from_key(
+    let actual_transfered_amount: U256 = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_TRANSFERRED_AMOUNT,
-        &vesting_type.to_string(),
-    );
+        vesting_type,
+    )
+    .transferred_amount;
     assert_eq!(actual_transfered_amount, transfer_amount);
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(vesting_status.vested_amount > U256::zero());
     assert_eq!(vesting_status.released_amount, transfer_amount);
@@ -285,12 +287,12 @@ fn should_allow_half_transfer_for_non_vesting_address_at_half_time() {
 
     let vesting_type = VestingType::Community;
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
     let sender = *test_accounts
@@ -299,7 +301,7 @@ fn should_allow_half_transfer_for_non_vesting_address_at_half_time() {
 
     let test_duration = DURATION_COMMUNITY_VESTING.map(|d| (d.whole_seconds() / 2) as u64);
     let transfer_amount = vesting_status.release_amount_per_period
-        * (test_duration.unwrap() / VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64);
+        * (test_duration.unwrap() / PERIOD_COMMUNITY_VESTING.whole_seconds() as u64);
 
     cowl_cep18_token_transfer(
         &mut builder,
@@ -312,20 +314,20 @@ fn should_allow_half_transfer_for_non_vesting_address_at_half_time() {
     .expect_success()
     .commit();
 
-    let actual_transfered_amount: U256 = get_dictionary_value_from_key(
+    let actual_transfered_amount: U256 = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_TRANSFERRED_AMOUNT,
-        &vesting_type.to_string(),
-    );
+        vesting_type,
+    )
+    .transferred_amount;
     assert_eq!(actual_transfered_amount, transfer_amount);
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(vesting_status.vested_amount > U256::zero());
     assert_eq!(vesting_status.released_amount, transfer_amount);
@@ -352,12 +354,12 @@ fn should_allow_full_transfer_for_non_vesting_address_at_full_time() {
 
     let vesting_type = VestingType::Community;
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
     let sender = *test_accounts
@@ -378,20 +380,20 @@ fn should_allow_full_transfer_for_non_vesting_address_at_full_time() {
     .expect_success()
     .commit();
 
-    let actual_transfered_amount: U256 = get_dictionary_value_from_key(
+    let actual_transfered_amount: U256 = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_TRANSFERRED_AMOUNT,
-        &vesting_type.to_string(),
-    );
+        vesting_type,
+    )
+    .transferred_amount;
     assert_eq!(actual_transfered_amount, transfer_amount);
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(vesting_status.vested_amount > U256::zero());
     assert_eq!(vesting_status.released_amount, transfer_amount);
@@ -405,3 +407,143 @@ fn should_allow_full_transfer_for_non_vesting_address_at_full_time() {
     );
     dbg!(vesting_status);
 }
+
+#[test]
+fn should_allow_transfer_of_initial_unlock_at_start_time() {
+    let vesting_type = VestingType::Community;
+    let initial_unlock_share: u16 = 1_000;
+
+    let mut vesting_configs = get_default_vesting_configs();
+    for vesting_config in vesting_configs.iter_mut() {
+        if vesting_config.vesting_type == vesting_type {
+            vesting_config.initial_unlock_share = initial_unlock_share;
+        }
+    }
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_VESTING_ALLOCATIONS => vesting_configs,
+        },
+        None,
+    );
+
+    let vesting_status: VestingStatus = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_status;
+
+    let expected_initial_unlock_amount = vesting_status.total_amount
+        * U256::from(initial_unlock_share)
+        / U256::from(BASIS_POINTS_DENOMINATOR);
+    let periods = DURATION_COMMUNITY_VESTING.unwrap().whole_seconds()
+        / PERIOD_COMMUNITY_VESTING.whole_seconds();
+
+    assert_eq!(
+        vesting_status.initial_unlock_amount,
+        expected_initial_unlock_amount
+    );
+    assert_eq!(
+        vesting_status.available_for_release_amount,
+        expected_initial_unlock_amount
+    );
+    assert_eq!(
+        vesting_status.release_amount_per_period,
+        (vesting_status.total_amount - expected_initial_unlock_amount) / U256::from(periods)
+    );
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let sender = *test_accounts
+        .get(&get_account_for_vesting(vesting_type))
+        .unwrap();
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &sender,
+        expected_initial_unlock_amount,
+        &account_user_1,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &sender,
+        U256::one(),
+        &account_user_1,
+        None,
+    )
+    .expect_failure();
+
+    let actual_transfered_amount: U256 = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .transferred_amount;
+    assert_eq!(actual_transfered_amount, expected_initial_unlock_amount);
+}
+
+#[test]
+fn should_read_vesting_record_once_and_write_it_once_per_vesting_transfer() {
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let vesting_type = VestingType::Community;
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let sender = *test_accounts
+        .get(&get_account_for_vesting(vesting_type))
+        .unwrap();
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &sender,
+        U256::one(),
+        &account_user_1,
+        Some(PERIOD_COMMUNITY_VESTING.whole_seconds() as u64),
+    )
+    .expect_success()
+    .commit();
+
+    // Past the lookup of the sender, the whole vesting type is a single record
+    let actual_accesses = get_vesting_dictionary_accesses(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        &[Key::from(sender), Key::from(account_user_1)],
+    );
+    let expected_accesses = BTreeMap::from([
+        (
+            (
+                DICT_VESTING_ADDRESSES.to_string(),
+                get_vesting_address_dictionary_key(&Key::from(sender)),
+            ),
+            (1, 0),
+        ),
+        (
+            (DICT_VESTING_RECORDS.to_string(), vesting_type.to_string()),
+            (1, 1),
+        ),
+    ]);
+    assert_eq!(actual_accesses, expected_accesses);
+}
@@ -3,14 +3,13 @@ use crate::utility::{
     installer_request_builders::{
         cowl_cep18_token_transfer, cowl_vesting_vesting_status, setup, TestContext,
     },
-    support::{get_account_for_vesting, get_dictionary_value_from_key},
+    support::{get_account_for_vesting, get_vesting_record},
 };
 use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
 use casper_types::{Key, U256};
 use cowl_vesting::{
     constants::{
-        DICT_TRANSFERRED_AMOUNT, DICT_VESTING_STATUS, DURATION_CONTRIBUTOR_VESTING,
-        VESTING_PERIOD_IN_SECONDS,
+        CLIFF_CONTRIBUTOR_VESTING, DURATION_CONTRIBUTOR_VESTING, PERIOD_CONTRIBUTOR_VESTING,
     },
     enums::VestingType,
     vesting::VestingStatus,
@@ -47,12 +46,12 @@ fn should_not_allow_transfer_for_non_vesting_address_at_zero_time() {
     )
     .expect_failure();
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert_eq!(vesting_status.vested_amount, U256::zero());
     assert_eq!(vesting_status.vesting_type, vesting_type);
@@ -64,7 +63,7 @@ fn should_not_allow_transfer_for_non_vesting_address_at_zero_time() {
 }
 
 #[test]
-fn should_allow_transfer_for_non_vesting_address_at_time_one_period() {
+fn should_allow_transfer_for_non_vesting_address_at_cliff_end() {
     let (
         mut builder,
         TestContext {
@@ -84,31 +83,42 @@ fn should_allow_transfer_for_non_vesting_address_at_time_one_period() {
 
     let transfer_amount = U256::one();
 
+    // A period has elapsed but the cliff holds it back
     cowl_cep18_token_transfer(
         &mut builder,
         &cowl_cep18_token_contract_hash,
         &sender,
         transfer_amount,
         &account_user_1,
-        Some(VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64),
+        Some(PERIOD_CONTRIBUTOR_VESTING.whole_seconds() as u64),
+    )
+    .expect_failure();
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &sender,
+        transfer_amount,
+        &account_user_1,
+        Some(CLIFF_CONTRIBUTOR_VESTING.unwrap().whole_seconds() as u64),
     )
     .expect_success()
     .commit();
 
-    let actual_transfered_amount: U256 = get_dictionary_value_from_key(
+    let actual_transfered_amount: U256 = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_TRANSFERRED_AMOUNT,
-        &vesting_type.to_string(),
-    );
+        vesting_type,
+    )
+    .transferred_amount;
     assert_eq!(actual_transfered_amount, transfer_amount);
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(vesting_status.vested_amount > U256::zero());
     assert_eq!(vesting_status.vesting_type, vesting_type);
@@ -120,7 +130,7 @@ fn should_allow_transfer_for_non_vesting_address_at_time_one_period() {
 }
 
 #[test]
-fn should_not_allow_transfer_for_more_than_vested_amount_at_time_one_period() {
+fn should_not_allow_transfer_for_more_than_vested_amount_at_cliff_end() {
     let (
         mut builder,
         TestContext {
@@ -138,14 +148,16 @@ fn should_not_allow_transfer_for_more_than_vested_amount_at_time_one_period() {
         .get(&get_account_for_vesting(vesting_type))
         .unwrap();
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
-    let transfer_amount = vesting_status.release_amount_per_period + 1;
+    let cliff_end = CLIFF_CONTRIBUTOR_VESTING.unwrap().whole_seconds() as u64;
+    let cliff_periods = cliff_end / PERIOD_CONTRIBUTOR_VESTING.whole_seconds() as u64;
+    let transfer_amount = vesting_status.release_amount_per_period * cliff_periods + 1;
 
     cowl_cep18_token_transfer(
         &mut builder,
@@ -153,7 +165,7 @@ fn should_not_allow_transfer_for_more_than_vested_amount_at_time_one_period() {
         &sender,
         transfer_amount,
         &account_user_1,
-        Some(VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64),
+        Some(cliff_end),
     )
     .expect_failure()
     .commit();
@@ -163,17 +175,17 @@ fn should_not_allow_transfer_for_more_than_vested_amount_at_time_one_period() {
         &cowl_vesting_contract_hash,
         &DEFAULT_ACCOUNT_ADDR,
         vesting_type,
-        Some(VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64),
+        Some(cliff_end),
     )
     .expect_success()
     .commit();
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert_eq!(vesting_status.vested_amount, transfer_amount - U256::one());
     assert_eq!(vesting_status.released_amount, U256::zero());
@@ -190,7 +202,7 @@ fn should_not_allow_transfer_for_more_than_vested_amount_at_time_one_period() {
 }
 
 #[test]
-fn should_allow_full_transfer_for_non_vesting_address_at_time_one_period() {
+fn should_allow_full_transfer_for_non_vesting_address_at_cliff_end() {
     let (
         mut builder,
         TestContext {
@@ -208,14 +220,16 @@ fn should_allow_full_transfer_for_non_vesting_address_at_time_one_period() {
         .get(&get_account_for_vesting(vesting_type))
         .unwrap();
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
-    let transfer_amount = vesting_status.release_amount_per_period;
+    let cliff_end = CLIFF_CONTRIBUTOR_VESTING.unwrap().whole_seconds() as u64;
+    let cliff_periods = cliff_end / PERIOD_CONTRIBUTOR_VESTING.whole_seconds() as u64;
+    let transfer_amount = vesting_status.release_amount_per_period * cliff_periods;
 
     cowl_cep18_token_transfer(
         &mut builder,
@@ -223,25 +237,25 @@ fn should_allow_full_transfer_for_non_vesting_address_at_time_one_period() {
         &sender,
         transfer_amount,
         &account_user_1,
-        Some(VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64),
+        Some(cliff_end),
     )
     .expect_success()
     .commit();
 
-    let actual_transfered_amount: U256 = get_dictionary_value_from_key(
+    let actual_transfered_amount: U256 = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_TRANSFERRED_AMOUNT,
-        &vesting_type.to_string(),
-    );
+        vesting_type,
+    )
+    .transferred_amount;
     assert_eq!(actual_transfered_amount, transfer_amount);
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(vesting_status.vested_amount > U256::zero());
     assert_eq!(vesting_status.released_amount, transfer_amount);
@@ -268,12 +282,12 @@ fn should_allow_half_transfer_for_non_vesting_address_at_half_time() {
 
     let vesting_type = VestingType::Contributor;
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
     let sender = *test_accounts
@@ -283,7 +297,7 @@ fn should_allow_half_transfer_for_non_vesting_address_at_half_time() {
     let test_duration = DURATION_CONTRIBUTOR_VESTING.map(|d| (d.whole_seconds() / 2) as u64);
 
     let transfer_amount = vesting_status.release_amount_per_period
-        * (test_duration.unwrap() / VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64);
+        * (test_duration.unwrap() / PERIOD_CONTRIBUTOR_VESTING.whole_seconds() as u64);
 
     cowl_cep18_token_transfer(
         &mut builder,
@@ -296,20 +310,20 @@ fn should_allow_half_transfer_for_non_vesting_address_at_half_time() {
     .expect_success()
     .commit();
 
-    let actual_transfered_amount: U256 = get_dictionary_value_from_key(
+    let actual_transfered_amount: U256 = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_TRANSFERRED_AMOUNT,
-        &vesting_type.to_string(),
-    );
+        vesting_type,
+    )
+    .transferred_amount;
     assert_eq!(actual_transfered_amount, transfer_amount);
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(vesting_status.vested_amount > U256::zero());
     assert_eq!(vesting_status.released_amount, transfer_amount);
@@ -336,12 +350,12 @@ fn should_allow_full_transfer_for_non_vesting_address_at_full_time() {
 
     let vesting_type = VestingType::Contributor;
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
     let sender = *test_accounts
@@ -362,20 +376,20 @@ fn should_allow_full_transfer_for_non_vesting_address_at_full_time() {
     .expect_success()
     .commit();
 
-    let actual_transfered_amount: U256 = get_dictionary_value_from_key(
+    let actual_transfered_amount: U256 = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_TRANSFERRED_AMOUNT,
-        &vesting_type.to_string(),
-    );
+        vesting_type,
+    )
+    .transferred_amount;
     assert_eq!(actual_transfered_amount, transfer_amount);
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(vesting_status.vested_amount > U256::zero());
     assert_eq!(vesting_status.released_amount, transfer_amount);
@@ -404,50 +418,59 @@ fn should_allow_full_transfer_at_regular_periods() {
 
     let vesting_type = VestingType::Contributor;
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
     let sender = *test_accounts
         .get(&get_account_for_vesting(vesting_type))
         .unwrap();
 
-    let transfer_amount = vesting_status.release_amount_per_period;
+    let release_amount_per_period = vesting_status.release_amount_per_period;
+    let cliff_periods = CLIFF_CONTRIBUTOR_VESTING.unwrap().whole_seconds() as u64
+        / PERIOD_CONTRIBUTOR_VESTING.whole_seconds() as u64;
+
+    // Periods accrued during the cliff are transferred at once when it ends
+    for month in cliff_periods..=12 {
+        let transfer_amount = if month == cliff_periods {
+            release_amount_per_period * cliff_periods
+        } else {
+            release_amount_per_period
+        };
 
-    for month in 1..=12 {
         cowl_cep18_token_transfer(
             &mut builder,
             &cowl_cep18_token_contract_hash,
             &sender,
             transfer_amount,
             &account_user_1,
-            Some(VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64 * month),
+            Some(PERIOD_CONTRIBUTOR_VESTING.whole_seconds() as u64 * month),
         )
         .expect_success()
         .commit();
 
-        let actual_transfered_amount: U256 = get_dictionary_value_from_key(
+        let actual_transfered_amount: U256 = get_vesting_record(
             &builder,
             &Key::from(cowl_vesting_contract_hash),
-            DICT_TRANSFERRED_AMOUNT,
-            &vesting_type.to_string(),
-        );
+            vesting_type,
+        )
+        .transferred_amount;
 
         assert_eq!(
             actual_transfered_amount,
-            transfer_amount * U256::from(month)
+            release_amount_per_period * U256::from(month)
         );
 
-        let vesting_status: VestingStatus = get_dictionary_value_from_key(
+        let vesting_status: VestingStatus = get_vesting_record(
             &builder,
             &Key::from(cowl_vesting_contract_hash),
-            DICT_VESTING_STATUS,
-            &vesting_type.to_string().to_owned(),
-        );
+            vesting_type,
+        )
+        .vesting_status;
 
         assert!(vesting_status.vested_amount > U256::zero());
 
@@ -497,12 +520,12 @@ fn should_allow_half_transfer_at_regular_periods() {
 
     let vesting_type = VestingType::Contributor;
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
     let sender = *test_accounts
@@ -510,51 +533,48 @@ fn should_allow_half_transfer_at_regular_periods() {
         .unwrap();
 
     let transfer_amount = vesting_status.release_amount_per_period / 2;
+    let cliff_periods = CLIFF_CONTRIBUTOR_VESTING.unwrap().whole_seconds() as u64
+        / PERIOD_CONTRIBUTOR_VESTING.whole_seconds() as u64;
+
+    for month in cliff_periods..=12 {
+        let transfers = U256::from(month - cliff_periods + 1);
 
-    for month in 1..=12 {
         cowl_cep18_token_transfer(
             &mut builder,
             &cowl_cep18_token_contract_hash,
             &sender,
             transfer_amount,
             &account_user_1,
-            Some(VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64 * month),
+            Some(PERIOD_CONTRIBUTOR_VESTING.whole_seconds() as u64 * month),
         )
         .expect_success()
         .commit();
 
-        let actual_transfered_amount: U256 = get_dictionary_value_from_key(
+        let actual_transfered_amount: U256 = get_vesting_record(
             &builder,
             &Key::from(cowl_vesting_contract_hash),
-            DICT_TRANSFERRED_AMOUNT,
-            &vesting_type.to_string(),
-        );
+            vesting_type,
+        )
+        .transferred_amount;
 
-        assert_eq!(
-            actual_transfered_amount,
-            transfer_amount * U256::from(month)
-        );
+        assert_eq!(actual_transfered_amount, transfer_amount * transfers);
 
-        let vesting_status: VestingStatus = get_dictionary_value_from_key(
+        let vesting_status: VestingStatus = get_vesting_record(
             &builder,
             &Key::from(cowl_vesting_contract_hash),
-            DICT_VESTING_STATUS,
-            &vesting_type.to_string().to_owned(),
-        );
+            vesting_type,
+        )
+        .vesting_status;
 
         assert!(vesting_status.vested_amount > U256::zero());
-        assert_eq!(
-            vesting_status.released_amount,
-            transfer_amount * U256::from(month)
-        );
+        assert_eq!(vesting_status.released_amount, transfer_amount * transfers);
 
         assert_eq!(vesting_status.vesting_type, vesting_type);
 
-        // Handle smallest unit rounding
         if !vesting_status.is_fully_vested {
-            assert!(
-                vesting_status.available_for_release_amount - transfer_amount * U256::from(month)
-                    <= U256::from(month)
+            assert_eq!(
+                vesting_status.available_for_release_amount,
+                vesting_status.vested_amount - vesting_status.released_amount
             );
         } else {
             assert_eq!(
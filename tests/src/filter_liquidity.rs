@@ -3,15 +3,15 @@ use std::time::Duration;
 use crate::utility::{
     constants::ACCOUNT_USER_1,
     installer_request_builders::{
-        cowl_cep18_token_transfer, cowl_vesting_vesting_status, setup, TestContext,
+        cowl_cep18_token_transfer, cowl_vesting_check_vesting_transfer,
+        cowl_vesting_preview_vesting_transfer, cowl_vesting_vesting_status, setup, TestContext,
     },
-    support::{get_account_for_vesting, get_dictionary_value_from_key},
+    support::{assert_expected_error, get_account_for_vesting, get_vesting_record},
 };
 use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
 use casper_types::{Key, U256};
 use cowl_vesting::{
-    constants::{DICT_VESTING_STATUS, VESTING_PERIOD_IN_SECONDS},
-    enums::VestingType,
+    constants::PERIOD_LIQUIDITY_VESTING, enums::VestingType, error::VestingError,
     vesting::VestingStatus,
 };
 
@@ -47,12 +47,12 @@ fn should_allow_transfer_for_non_vesting_address_at_zero_time() {
     .expect_success()
     .commit();
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert_eq!(vesting_status.vested_amount, U256::zero());
     assert_eq!(vesting_status.vesting_type, vesting_type);
@@ -87,17 +87,17 @@ fn should_allow_transfer_for_non_vesting_address_at_time_one_period() {
         &sender,
         transfer_amount,
         &account_user_1,
-        Some(VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64),
+        Some(PERIOD_LIQUIDITY_VESTING.whole_seconds() as u64),
     )
     .expect_success()
     .commit();
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert_eq!(vesting_status.vested_amount, U256::zero());
     assert_eq!(vesting_status.vesting_type, vesting_type);
@@ -124,12 +124,12 @@ fn should_not_allow_transfer_for_more_than_total_amount_at_time_one_period() {
         .get(&get_account_for_vesting(vesting_type))
         .unwrap();
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     let transfer_amount = vesting_status.total_amount + 1;
 
@@ -139,7 +139,7 @@ fn should_not_allow_transfer_for_more_than_total_amount_at_time_one_period() {
         &sender,
         transfer_amount,
         &account_user_1,
-        Some(VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64),
+        Some(PERIOD_LIQUIDITY_VESTING.whole_seconds() as u64),
     )
     .expect_failure();
 
@@ -148,17 +148,17 @@ fn should_not_allow_transfer_for_more_than_total_amount_at_time_one_period() {
         &cowl_vesting_contract_hash,
         &DEFAULT_ACCOUNT_ADDR,
         vesting_type,
-        Some(VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64),
+        Some(PERIOD_LIQUIDITY_VESTING.whole_seconds() as u64),
     )
     .expect_success()
     .commit();
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert_eq!(vesting_status.vested_amount, U256::zero());
     assert_eq!(vesting_status.released_amount, U256::zero());
@@ -190,12 +190,12 @@ fn should_allow_full_transfer_for_non_vesting_address_at_time_one_period() {
         .get(&get_account_for_vesting(vesting_type))
         .unwrap();
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     let transfer_amount = vesting_status.total_amount;
 
@@ -205,17 +205,17 @@ fn should_allow_full_transfer_for_non_vesting_address_at_time_one_period() {
         &sender,
         transfer_amount,
         &account_user_1,
-        Some(VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64),
+        Some(PERIOD_LIQUIDITY_VESTING.whole_seconds() as u64),
     )
     .expect_success()
     .commit();
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert_eq!(vesting_status.vested_amount, U256::zero());
     assert_eq!(vesting_status.released_amount, transfer_amount);
@@ -239,12 +239,12 @@ fn should_allow_half_transfer_for_non_vesting_address_at_half_time() {
 
     let vesting_type = VestingType::Liquidity;
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
     let sender = *test_accounts
@@ -259,17 +259,17 @@ fn should_allow_half_transfer_for_non_vesting_address_at_half_time() {
         &sender,
         transfer_amount,
         &account_user_1,
-        Some(VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64 / 2),
+        Some(PERIOD_LIQUIDITY_VESTING.whole_seconds() as u64 / 2),
     )
     .expect_success()
     .commit();
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert_eq!(vesting_status.vested_amount, U256::zero());
     assert_eq!(vesting_status.released_amount, transfer_amount);
@@ -293,12 +293,12 @@ fn should_allow_full_transfer_for_non_vesting_address_at_full_time() {
 
     let vesting_type = VestingType::Liquidity;
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
     let sender = *test_accounts
@@ -313,17 +313,17 @@ fn should_allow_full_transfer_for_non_vesting_address_at_full_time() {
         &sender,
         transfer_amount,
         &account_user_1,
-        Some(VESTING_PERIOD_IN_SECONDS.whole_seconds() as u64),
+        Some(PERIOD_LIQUIDITY_VESTING.whole_seconds() as u64),
     )
     .expect_success()
     .commit();
 
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &vesting_type.to_string().to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
     assert_eq!(vesting_status.vested_amount, U256::zero());
     assert_eq!(vesting_status.released_amount, transfer_amount);
     assert_eq!(vesting_status.available_for_release_amount, U256::zero());
@@ -332,3 +332,60 @@ fn should_allow_full_transfer_for_non_vesting_address_at_full_time() {
     assert_eq!(vesting_status.vesting_duration, Duration::ZERO);
     //dbg!(vesting_status);
 }
+
+#[test]
+fn should_only_record_vesting_transfer_checks_from_token_contract() {
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let vesting_type = VestingType::Liquidity;
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let vesting_account = *test_accounts
+        .get(&get_account_for_vesting(vesting_type))
+        .unwrap();
+
+    let transfer_amount = U256::one();
+
+    cowl_vesting_check_vesting_transfer(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        &vesting_account,
+        &account_user_1,
+        transfer_amount,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InvalidTransferFilterCaller as u16,
+        "only the token contract can check vesting transfers",
+    );
+
+    cowl_vesting_preview_vesting_transfer(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        &vesting_account,
+        &account_user_1,
+        transfer_amount,
+    )
+    .expect_success()
+    .commit();
+
+    let transferred_amount = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .transferred_amount;
+    assert_eq!(transferred_amount, U256::zero());
+}
@@ -0,0 +1,691 @@
+use crate::utility::{
+    constants::{ACCOUNT_USER_1, ACCOUNT_USER_2, VESTING_CONTRACT_PACKAGE_HASH_KEY_NAME},
+    installer_request_builders::{
+        cowl_cep18_token_increase_allowance, cowl_cep18_token_transfer, cowl_vesting_claim,
+        cowl_vesting_create_contributor_grant, cowl_vesting_create_grant,
+        cowl_vesting_grant_status, cowl_vesting_revoke, setup, setup_with_args, TestContext,
+    },
+    support::{
+        assert_expected_error, get_account_for_vesting, get_event, get_vesting_dictionary_accesses,
+        get_vesting_grant_record, get_vesting_record,
+    },
+};
+use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use cowl_vesting::{
+    constants::{
+        ARG_EVENTS_MODE, ARG_TREASURY_ADDRESS, ARG_VESTING_MODE, DICT_VESTING_ADDRESSES,
+        DICT_VESTING_GRANTS, DICT_VESTING_RECORDS, MONTH_IN_SECONDS, YEAR_IN_SECONDS,
+    },
+    enums::{EventsMode, PeriodMode, ReleaseCurve, VestingMode, VestingType},
+    error::VestingError,
+    events::{GrantCreation, GrantRevocation, VestingRelease},
+    vesting::{get_vesting_grant_key, VestingGrant, VestingStatus},
+};
+use std::collections::BTreeMap;
+use time::Duration;
+
+fn get_vesting_grant(beneficiary: Key, funding_type: VestingType, amount: U256) -> VestingGrant {
+    VestingGrant {
+        beneficiary,
+        funding_type,
+        amount,
+        start_time: Some(MONTH_IN_SECONDS),
+        vesting_duration: Some(Duration::seconds(YEAR_IN_SECONDS as i64)),
+        cliff_duration: None,
+        vesting_period: Duration::seconds(MONTH_IN_SECONDS as i64),
+        period_mode: PeriodMode::Fixed,
+        release_curve: ReleaseCurve::Stepwise,
+        initial_unlock_share: 0,
+        tranches: Vec::new(),
+        is_revocable: false,
+        revoke_time: None,
+    }
+}
+
+#[test]
+fn should_vest_grant_funded_from_vesting_type() {
+    let funding_type = VestingType::Treasury;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let funding_account = *test_accounts
+        .get(&get_account_for_vesting(funding_type))
+        .unwrap();
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+
+    let funding_amount: U256 = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        funding_type,
+    )
+    .vesting_amount;
+
+    let cowl_vesting_contract_package_key = *builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(VESTING_CONTRACT_PACKAGE_HASH_KEY_NAME)
+        .expect("should have package hash");
+
+    let grant_amount = U256::from(12_000_000_000_u64);
+
+    // The grant is funded when created
+    cowl_vesting_create_grant(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        get_vesting_grant(Key::from(account_user_1), funding_type, grant_amount),
+    )
+    .expect_failure();
+
+    // The funding vesting address lets the vesting contract fund the grant
+    cowl_cep18_token_increase_allowance(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &funding_account,
+        cowl_vesting_contract_package_key,
+        grant_amount,
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_create_grant(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        get_vesting_grant(Key::from(account_user_1), funding_type, grant_amount),
+    )
+    .expect_success()
+    .commit();
+
+    // Funding the grant is checked by the transfer filter first
+    let expected_event = GrantCreation::new(
+        Key::from(*DEFAULT_ACCOUNT_ADDR),
+        Key::from(account_user_1),
+        funding_type.to_string(),
+        grant_amount,
+        MONTH_IN_SECONDS,
+    );
+    let actual_event: GrantCreation = get_event(&builder, &cowl_vesting_contract_hash.into(), 1);
+    assert_eq!(actual_event, expected_event);
+
+    // The grant is taken out of the allocation of the funding vesting type, funding it does not
+    // depend on the schedule of the funding vesting type
+    let actual_funding_amount: U256 = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        funding_type,
+    )
+    .vesting_amount;
+    assert_eq!(actual_funding_amount, funding_amount - grant_amount);
+
+    // Nothing is vested before the first release period ends
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &account_user_1,
+        U256::one(),
+        &account_user_2,
+        Some(MONTH_IN_SECONDS),
+    )
+    .expect_failure();
+
+    let release_amount_per_period = grant_amount / U256::from(12);
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &account_user_1,
+        release_amount_per_period,
+        &account_user_2,
+        Some(2 * MONTH_IN_SECONDS),
+    )
+    .expect_success()
+    .commit();
+
+    // The grant is a single record, its funding vesting type is only read for its pause and
+    // acceleration
+    let actual_accesses = get_vesting_dictionary_accesses(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        &[Key::from(account_user_1), Key::from(account_user_2)],
+    );
+    let grant_key = get_vesting_grant_key(&Key::from(account_user_1));
+    let expected_accesses = BTreeMap::from([
+        (
+            (DICT_VESTING_ADDRESSES.to_string(), grant_key.clone()),
+            (1, 0),
+        ),
+        ((DICT_VESTING_GRANTS.to_string(), grant_key), (1, 1)),
+        (
+            (DICT_VESTING_RECORDS.to_string(), funding_type.to_string()),
+            (1, 0),
+        ),
+    ]);
+    assert_eq!(actual_accesses, expected_accesses);
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &account_user_1,
+        U256::one(),
+        &account_user_2,
+        Some(2 * MONTH_IN_SECONDS),
+    )
+    .expect_failure();
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &account_user_1,
+        grant_amount - release_amount_per_period,
+        &account_user_2,
+        Some(MONTH_IN_SECONDS + YEAR_IN_SECONDS),
+    )
+    .expect_success()
+    .commit();
+}
+
+#[test]
+fn should_not_create_grant_without_admin_rights() {
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    cowl_vesting_create_grant(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        get_vesting_grant(
+            Key::from(account_user_1),
+            VestingType::Treasury,
+            U256::one(),
+        ),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InsufficientRights as u16,
+        "only admins can create grants",
+    );
+}
+
+#[test]
+fn should_not_create_grant_above_funding_allocation_or_twice() {
+    let funding_type = VestingType::Contributor;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let funding_account = *test_accounts
+        .get(&get_account_for_vesting(funding_type))
+        .unwrap();
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    let cowl_vesting_contract_package_key = *builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(VESTING_CONTRACT_PACKAGE_HASH_KEY_NAME)
+        .expect("should have package hash");
+
+    let funding_amount: U256 = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        funding_type,
+    )
+    .vesting_amount;
+
+    cowl_cep18_token_increase_allowance(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &funding_account,
+        cowl_vesting_contract_package_key,
+        funding_amount,
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_create_grant(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        get_vesting_grant(
+            Key::from(account_user_1),
+            funding_type,
+            funding_amount + U256::one(),
+        ),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InsufficientVestingAmount as u16,
+        "should not grant more than the funding allocation",
+    );
+
+    cowl_vesting_create_grant(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        get_vesting_grant(Key::from(account_user_1), funding_type, funding_amount),
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_create_grant(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        get_vesting_grant(Key::from(account_user_1), funding_type, U256::one()),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::VestingGrantAlreadyExists as u16,
+        "should not create a second grant for the same beneficiary",
+    );
+}
+
+#[test]
+fn should_vest_contributor_grant_from_its_own_cliff() {
+    let funding_type = VestingType::Contributor;
+    let start_time = MONTH_IN_SECONDS;
+    let cliff_duration = 3 * MONTH_IN_SECONDS;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let funding_account = *test_accounts
+        .get(&get_account_for_vesting(funding_type))
+        .unwrap();
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let beneficiary = Key::from(account_user_1);
+
+    let cowl_vesting_contract_package_key = *builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(VESTING_CONTRACT_PACKAGE_HASH_KEY_NAME)
+        .expect("should have package hash");
+
+    let funding_amount: U256 = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        funding_type,
+    )
+    .vesting_amount;
+
+    let grant_amount = U256::from(12_000_000_000_u64);
+
+    cowl_cep18_token_increase_allowance(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &funding_account,
+        cowl_vesting_contract_package_key,
+        grant_amount,
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_create_contributor_grant(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        beneficiary,
+        grant_amount,
+        Some(start_time),
+        Some(cliff_duration),
+    )
+    .expect_success()
+    .commit();
+
+    let actual_funding_amount: U256 = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        funding_type,
+    )
+    .vesting_amount;
+    assert_eq!(actual_funding_amount, funding_amount - grant_amount);
+
+    // Contributor schedule vests monthly, nothing is available before the grant cliff ends
+    cowl_vesting_grant_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        beneficiary,
+        Some(start_time + 2 * MONTH_IN_SECONDS),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_status: VestingStatus = get_vesting_grant_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        &beneficiary,
+    )
+    .vesting_status;
+
+    assert_eq!(vesting_status.total_amount, grant_amount);
+    assert_eq!(vesting_status.available_for_release_amount, U256::zero());
+    assert_eq!(
+        vesting_status.cliff_end,
+        std::time::Duration::from_secs(start_time + cliff_duration)
+    );
+
+    cowl_vesting_grant_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        beneficiary,
+        Some(start_time + cliff_duration),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_status: VestingStatus = get_vesting_grant_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        &beneficiary,
+    )
+    .vesting_status;
+
+    let expected_vested_amount = grant_amount / U256::from(12) * U256::from(3);
+    assert_eq!(vesting_status.vested_amount, expected_vested_amount);
+    assert_eq!(
+        vesting_status.available_for_release_amount,
+        expected_vested_amount
+    );
+}
+
+#[test]
+fn should_revoke_grant_and_recover_unvested_remainder() {
+    let funding_type = VestingType::Treasury;
+    let treasury_address = Key::Hash([7u8; 32]);
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_EVENTS_MODE => EventsMode::CES as u8,
+            ARG_VESTING_MODE => VestingMode::Custody as u8,
+            ARG_TREASURY_ADDRESS => Some(treasury_address),
+        },
+        None,
+    );
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let beneficiary = Key::from(account_user_1);
+
+    let grant_amount = U256::from(12_000_000_000_u64);
+    let release_amount_per_period = grant_amount / U256::from(12);
+
+    // The grant stays in custody until claimed
+    cowl_vesting_create_grant(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        VestingGrant {
+            is_revocable: true,
+            ..get_vesting_grant(beneficiary, funding_type, grant_amount)
+        },
+    )
+    .expect_success()
+    .commit();
+
+    // Only admins can revoke
+    cowl_vesting_revoke(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_2,
+        beneficiary,
+        None,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InsufficientRights as u16,
+        "only admins can revoke a grant",
+    );
+
+    // Three periods have vested when the grant is revoked
+    let revoke_time = 4 * MONTH_IN_SECONDS;
+    let vested_amount = release_amount_per_period * U256::from(3);
+
+    cowl_vesting_revoke(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        beneficiary,
+        Some(revoke_time),
+    )
+    .expect_success()
+    .commit();
+
+    let expected_event = GrantRevocation::new(
+        Key::from(*DEFAULT_ACCOUNT_ADDR),
+        beneficiary,
+        vested_amount,
+        grant_amount - vested_amount,
+        treasury_address,
+        revoke_time,
+    );
+    let actual_event: GrantRevocation = get_event(&builder, &cowl_vesting_contract_hash.into(), 1);
+    assert_eq!(actual_event, expected_event);
+
+    // Vesting is frozen at the vested amount
+    cowl_vesting_grant_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        beneficiary,
+        Some(MONTH_IN_SECONDS + YEAR_IN_SECONDS),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_status: VestingStatus = get_vesting_grant_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        &beneficiary,
+    )
+    .vesting_status;
+
+    assert!(vesting_status.is_revoked);
+    assert_eq!(
+        vesting_status.revoke_time,
+        std::time::Duration::from_secs(revoke_time)
+    );
+    assert_eq!(vesting_status.total_amount, vested_amount);
+    assert_eq!(vesting_status.available_for_release_amount, vested_amount);
+
+    // Only the vested amount is left to claim
+    cowl_vesting_claim(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        Some(MONTH_IN_SECONDS + YEAR_IN_SECONDS),
+    )
+    .expect_success()
+    .commit();
+
+    let expected_event = VestingRelease::new(beneficiary, funding_type.to_string(), vested_amount);
+    let actual_event: VestingRelease = get_event(&builder, &cowl_vesting_contract_hash.into(), 2);
+    assert_eq!(actual_event, expected_event);
+
+    // A grant is revoked once
+    cowl_vesting_revoke(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        beneficiary,
+        None,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::VestingGrantRevoked as u16,
+        "should not revoke a grant twice",
+    );
+}
+
+#[test]
+fn should_not_revoke_irrevocable_grant_or_without_treasury_address() {
+    let funding_type = VestingType::Treasury;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_EVENTS_MODE => EventsMode::CES as u8,
+            ARG_VESTING_MODE => VestingMode::Custody as u8,
+        },
+        None,
+    );
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+
+    let grant_amount = U256::from(12_000_000_000_u64);
+
+    cowl_vesting_create_grant(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        get_vesting_grant(Key::from(account_user_1), funding_type, grant_amount),
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_revoke(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        Key::from(account_user_1),
+        None,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::VestingGrantNotRevocable as u16,
+        "should not revoke an irrevocable grant",
+    );
+
+    cowl_vesting_create_grant(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        VestingGrant {
+            is_revocable: true,
+            ..get_vesting_grant(Key::from(account_user_2), funding_type, grant_amount)
+        },
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_revoke(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        Key::from(account_user_2),
+        None,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::MissingTreasuryAddress as u16,
+        "should not revoke without a treasury address",
+    );
+}
+
+#[test]
+fn should_not_create_revocable_grant_in_transfer_mode() {
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    // The beneficiary would hold the grant, its remainder could not be recovered
+    cowl_vesting_create_grant(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        VestingGrant {
+            is_revocable: true,
+            ..get_vesting_grant(
+                Key::from(account_user_1),
+                VestingType::Treasury,
+                U256::one(),
+            )
+        },
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::RevocableGrantRequiresCustody as u16,
+        "revocable grants are held in custody",
+    );
+}
@@ -4,8 +4,8 @@ use crate::utility::{
         ACCOUNT_STACKING, ACCOUNT_TREASURY, VESTING_CONTRACT_VERSION, VESTING_CONTRACT_WASM,
         VESTING_TEST_NAME,
     },
-    installer_request_builders::{setup, TestContext},
-    support::{get_account_for_vesting, get_dictionary_value_from_key},
+    installer_request_builders::{setup, setup_with_args, TestContext},
+    support::{assert_expected_error, get_account_for_vesting, get_vesting_record},
 };
 use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
 use casper_types::{runtime_args, ContractPackageHash, Key, RuntimeArgs, U256};
@@ -13,12 +13,14 @@ use cowl_vesting::{
     constants::{
         ARG_CONTRACT_HASH, ARG_COWL_CEP18_CONTRACT_PACKAGE, ARG_EVENTS_MODE, ARG_INSTALLER,
         ARG_NAME, ARG_PACKAGE_HASH, ARG_TOTAL_SUPPLY, ARG_TRANSFER_FILTER_CONTRACT_PACKAGE,
-        ARG_TRANSFER_FILTER_METHOD, DICT_ADDRESSES, DICT_SECURITY_BADGES, DICT_START_TIME,
-        DICT_VESTING_AMOUNT, DICT_VESTING_STATUS,
+        ARG_TRANSFER_FILTER_METHOD, ARG_VESTING_ALLOCATIONS, BASIS_POINTS_DENOMINATOR,
+        DICT_SECURITY_BADGES, MONTH_IN_SECONDS, NONE_LIST,
     },
-    enums::{EventsMode, VestingType, VESTING_INFO},
-    vesting::VestingStatus,
+    enums::{EventsMode, VestingType, VESTING_DUST_RECIPIENT, VESTING_INFO},
+    error::VestingError,
+    vesting::{get_default_vesting_configs, VestingConfig, VestingInfo, VestingStatus},
 };
+use time::Duration;
 
 #[test]
 fn should_install_contract() {
@@ -105,12 +107,14 @@ fn should_install_contract() {
 
     // Check all vesting addresses in dictionary
     for vesting_info in VESTING_INFO.iter() {
-        let actual_address = *get_dictionary_value_from_key::<Key>(
+        let actual_address = *get_vesting_record(
             &builder,
             &Key::from(cowl_vesting_contract_hash),
-            DICT_ADDRESSES,
-            &vesting_info.vesting_type.to_string(),
+            vesting_info.vesting_type,
         )
+        .vesting_info
+        .maybe_vesting_address_key
+        .unwrap()
         .as_account()
         .unwrap();
 
@@ -126,12 +130,12 @@ fn should_install_contract() {
             vesting_info.vesting_type
         );
 
-        let vesting_status: VestingStatus = get_dictionary_value_from_key(
+        let vesting_status: VestingStatus = get_vesting_record(
             &builder,
             &Key::from(cowl_vesting_contract_hash),
-            DICT_VESTING_STATUS,
-            &vesting_info.vesting_type.to_string().to_owned(),
-        );
+            vesting_info.vesting_type,
+        )
+        .vesting_status;
         assert_eq!(vesting_status.vesting_type, vesting_info.vesting_type);
 
         dbg!(vesting_status);
@@ -140,20 +144,20 @@ fn should_install_contract() {
     let total_vested_amount: U256 = VESTING_INFO
         .iter()
         .map(|vesting_info| {
-            let actual_amount: U256 = get_dictionary_value_from_key::<U256>(
+            let actual_amount: U256 = get_vesting_record(
                 &builder,
                 &Key::from(cowl_vesting_contract_hash),
-                DICT_VESTING_AMOUNT,
-                &vesting_info.vesting_type.to_string(),
-            );
+                vesting_info.vesting_type,
+            )
+            .vesting_amount;
 
             // Perform the check for the start time as well
-            let actual_start_time: u64 = get_dictionary_value_from_key::<u64>(
+            let actual_start_time: u64 = get_vesting_record(
                 &builder,
                 &Key::from(cowl_vesting_contract_hash),
-                DICT_START_TIME,
-                &vesting_info.vesting_type.to_string(),
-            );
+                vesting_info.vesting_type,
+            )
+            .start_time;
 
             // Assert the start time for the current vesting info
             assert_eq!(
@@ -269,3 +273,170 @@ fn should_prevent_reinstall_contract() {
 
     assert_eq!(named_keys, new_named_keys)
 }
+
+#[test]
+fn should_install_contract_with_custom_vesting_allocations() {
+    let mut vesting_configs: Vec<VestingConfig> = get_default_vesting_configs();
+    for vesting_config in vesting_configs.iter_mut() {
+        match vesting_config.vesting_type {
+            VestingType::Liquidity => vesting_config.vesting_share = 1_950,
+            VestingType::Development => vesting_config.vesting_share = 1_250,
+            VestingType::Treasury => vesting_config.vesting_share = 2_950,
+            VestingType::Community => vesting_config.vesting_share = 2_850,
+            VestingType::Contributor => {
+                vesting_config.cliff_duration = Some(Duration::seconds(3 * MONTH_IN_SECONDS as i64))
+            }
+            _ => {}
+        }
+    }
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_VESTING_ALLOCATIONS => vesting_configs.clone(),
+        },
+        None,
+    );
+
+    let total_supply: U256 =
+        builder.get_value::<U256>(cowl_cep18_token_contract_hash, ARG_TOTAL_SUPPLY);
+
+    let mut total_vested_amount = U256::zero();
+
+    for vesting_config in vesting_configs.iter() {
+        let actual_amount: U256 = get_vesting_record(
+            &builder,
+            &Key::from(cowl_vesting_contract_hash),
+            vesting_config.vesting_type,
+        )
+        .vesting_amount;
+        let expected_amount = total_supply * U256::from(vesting_config.vesting_share)
+            / U256::from(BASIS_POINTS_DENOMINATOR);
+
+        // Only the dust recipient may receive more than its floored share
+        if vesting_config.vesting_type == VESTING_DUST_RECIPIENT {
+            assert!(actual_amount >= expected_amount);
+        } else {
+            assert_eq!(
+                actual_amount, expected_amount,
+                "Mismatch for {:?}",
+                vesting_config.vesting_type
+            );
+        }
+        total_vested_amount += actual_amount;
+
+        let vesting_info: VestingInfo = get_vesting_record(
+            &builder,
+            &Key::from(cowl_vesting_contract_hash),
+            vesting_config.vesting_type,
+        )
+        .vesting_info;
+        assert_eq!(
+            vesting_info.vesting_duration,
+            vesting_config.vesting_duration
+        );
+        assert_eq!(vesting_info.cliff_duration, vesting_config.cliff_duration);
+        assert_eq!(vesting_info.vesting_period, vesting_config.vesting_period);
+    }
+
+    assert_eq!(
+        total_vested_amount, total_supply,
+        "The total vested amount does not match the token total supply!"
+    );
+}
+
+#[test]
+fn should_not_install_contract_with_invalid_vesting_allocations_total() {
+    let (
+        mut builder,
+        TestContext {
+            cowl_cep18_token_package_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let mut vesting_configs: Vec<VestingConfig> = get_default_vesting_configs();
+    for vesting_config in vesting_configs.iter_mut() {
+        if vesting_config.vesting_type == VestingType::Treasury {
+            vesting_config.vesting_share += 1;
+        }
+    }
+
+    let mut install_args = runtime_args!(
+        ARG_NAME => "invalid_allocations",
+        ARG_EVENTS_MODE => EventsMode::CES as u8,
+        ARG_COWL_CEP18_CONTRACT_PACKAGE => Key::from(cowl_cep18_token_package_hash),
+        ARG_VESTING_ALLOCATIONS => vesting_configs,
+    );
+
+    for vesting_info in VESTING_INFO.iter() {
+        let account = get_account_for_vesting(vesting_info.vesting_type);
+        let _ = install_args.insert(
+            vesting_info.vesting_type.to_string(),
+            Key::from(*test_accounts.get(&account).unwrap()),
+        );
+    }
+
+    let install_request_contract =
+        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, VESTING_CONTRACT_WASM, install_args)
+            .build();
+
+    builder.exec(install_request_contract).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        VestingError::InvalidVestingAllocationsTotal as u16,
+        "should not install with shares not summing to 100%",
+    );
+}
+
+#[test]
+fn should_not_install_contract_without_admin() {
+    let (
+        mut builder,
+        TestContext {
+            cowl_cep18_token_package_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    // No admin list makes the installer admin, the none list then demotes it
+    let mut install_args = runtime_args!(
+        ARG_NAME => "no_admin",
+        ARG_EVENTS_MODE => EventsMode::CES as u8,
+        ARG_COWL_CEP18_CONTRACT_PACKAGE => Key::from(cowl_cep18_token_package_hash),
+        NONE_LIST => vec![Key::from(*DEFAULT_ACCOUNT_ADDR)],
+    );
+
+    for vesting_info in VESTING_INFO.iter() {
+        let account = get_account_for_vesting(vesting_info.vesting_type);
+        let _ = install_args.insert(
+            vesting_info.vesting_type.to_string(),
+            Key::from(*test_accounts.get(&account).unwrap()),
+        );
+    }
+
+    let install_request_contract =
+        ExecuteRequestBuilder::standard(*DEFAULT_ACCOUNT_ADDR, VESTING_CONTRACT_WASM, install_args)
+            .build();
+
+    builder.exec(install_request_contract).expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+
+    assert_expected_error(
+        error,
+        VestingError::LastAdminRemoval as u16,
+        "should not install without any admin",
+    );
+}
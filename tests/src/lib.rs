@@ -28,6 +28,30 @@ mod filter_community;
 #[cfg(test)]
 mod filter_liquidity;
 
+#[cfg(test)]
+mod start_time;
+
+#[cfg(test)]
+mod milestones;
+
+#[cfg(test)]
+mod grants;
+
+#[cfg(test)]
+mod vesting_address;
+
+#[cfg(test)]
+mod shared_addresses;
+
+#[cfg(test)]
+mod claim;
+
+#[cfg(test)]
+mod pause;
+
+#[cfg(test)]
+mod acceleration;
+
 #[cfg(any(test, feature = "test-support"))]
 pub use utility::constants;
 #[cfg(any(test, feature = "test-support"))]
@@ -41,17 +65,320 @@ pub use utility::support;
 
 #[cfg(test)]
 mod tests {
-    use cowl_vesting::enums::VESTING_PERCENTAGES;
+    use casper_types::{
+        account::AccountHash,
+        bytesrepr::{FromBytes, ToBytes},
+        Key, U256,
+    };
+    use cowl_vesting::{
+        constants::{
+            BASIS_POINTS_DENOMINATOR, MONTH_IN_SECONDS, VESTING_GRANT_RECORD_VERSION,
+            VESTING_RECORD_VERSION, YEAR_IN_SECONDS,
+        },
+        enums::{PeriodMode, ReleaseCurve, VestingType, VESTING_SHARES},
+        error::VestingError,
+        utils::{add_calendar_months, calendar_months_in_period, elapsed_calendar_months},
+        vesting::{
+            get_default_vesting_configs, validate_vesting_configs, validate_vesting_grant,
+            VestingGrant, VestingGrantRecord, VestingMilestone, VestingRecord, VestingStatus,
+            VestingTranche,
+        },
+    };
+    use time::{macros::datetime, Duration};
+    #[test]
+    fn test_vesting_shares_sum_to_100_percent() {
+        let total_share: u16 = VESTING_SHARES.iter().map(|&(_, share)| share).sum();
+        assert_eq!(
+            total_share, BASIS_POINTS_DENOMINATOR,
+            "VESTING_SHARES does not sum to 100%, actual total: {} bps",
+            total_share
+        );
+    }
+
+    #[test]
+    fn test_default_vesting_configs_are_valid() {
+        assert!(validate_vesting_configs(&get_default_vesting_configs()).is_ok());
+    }
+
+    #[test]
+    fn test_vesting_tranches_validation() {
+        let month = Duration::seconds(MONTH_IN_SECONDS as i64);
+        let tranches = vec![
+            VestingTranche {
+                release_offset: Duration::ZERO,
+                cumulative_share: 1_000,
+            },
+            VestingTranche {
+                release_offset: month * 3,
+                cumulative_share: 2_500,
+            },
+            VestingTranche {
+                release_offset: month * 12,
+                cumulative_share: BASIS_POINTS_DENOMINATOR,
+            },
+        ];
+
+        let with_tranches = |tranches: Vec<VestingTranche>| {
+            let mut vesting_configs = get_default_vesting_configs();
+            for vesting_config in vesting_configs.iter_mut() {
+                if vesting_config.vesting_type == VestingType::Community {
+                    vesting_config.release_curve = ReleaseCurve::Tranches;
+                    vesting_config.vesting_duration = Some(month * 12);
+                    vesting_config.tranches = tranches.clone();
+                }
+            }
+            validate_vesting_configs(&vesting_configs)
+        };
+
+        assert!(with_tranches(tranches.clone()).is_ok());
+
+        // Not ending at 100%
+        let mut incomplete_tranches = tranches.clone();
+        incomplete_tranches[2].cumulative_share = 9_000;
+        assert!(matches!(
+            with_tranches(incomplete_tranches),
+            Err(VestingError::InvalidVestingTranches)
+        ));
+
+        // Unlock dates out of order
+        let mut unordered_tranches = tranches.clone();
+        unordered_tranches.swap(0, 1);
+        assert!(matches!(
+            with_tranches(unordered_tranches),
+            Err(VestingError::InvalidVestingTranches)
+        ));
+
+        // Tranches on a stepwise schedule
+        let mut vesting_configs = get_default_vesting_configs();
+        vesting_configs[0].tranches = tranches;
+        assert!(matches!(
+            validate_vesting_configs(&vesting_configs),
+            Err(VestingError::InvalidVestingTranches)
+        ));
+    }
+
+    #[test]
+    fn test_vesting_milestones_validation() {
+        let milestone = |name: &str, share: u16| VestingMilestone {
+            name: name.to_string(),
+            share,
+            released_at: None,
+        };
+
+        let with_milestones = |milestones: Vec<VestingMilestone>| {
+            let mut vesting_configs = get_default_vesting_configs();
+            for vesting_config in vesting_configs.iter_mut() {
+                if vesting_config.vesting_type == VestingType::Development {
+                    vesting_config.release_curve = ReleaseCurve::Milestones;
+                    vesting_config.milestones = milestones.clone();
+                }
+            }
+            validate_vesting_configs(&vesting_configs)
+        };
+
+        assert!(
+            with_milestones(vec![milestone("mainnet", 4_000), milestone("audit", 6_000)]).is_ok()
+        );
+
+        // Duplicated names
+        assert!(matches!(
+            with_milestones(vec![
+                milestone("mainnet", 4_000),
+                milestone("mainnet", 6_000)
+            ]),
+            Err(VestingError::InvalidVestingMilestones)
+        ));
+
+        // Shares not adding up to 100%
+        assert!(matches!(
+            with_milestones(vec![milestone("mainnet", 4_000)]),
+            Err(VestingError::InvalidVestingMilestones)
+        ));
+    }
+
+    #[test]
+    fn test_vesting_grant_validation() {
+        let vesting_grant = VestingGrant {
+            beneficiary: Key::Account(AccountHash::new([1u8; 32])),
+            funding_type: VestingType::Treasury,
+            amount: U256::from(1_000),
+            start_time: None,
+            vesting_duration: Some(Duration::seconds(YEAR_IN_SECONDS as i64)),
+            cliff_duration: None,
+            vesting_period: Duration::seconds(MONTH_IN_SECONDS as i64),
+            period_mode: PeriodMode::Fixed,
+            release_curve: ReleaseCurve::Stepwise,
+            initial_unlock_share: 0,
+            tranches: Vec::new(),
+            is_revocable: false,
+            revoke_time: None,
+        };
+
+        assert!(validate_vesting_grant(&vesting_grant).is_ok());
+
+        // Empty grant
+        assert!(matches!(
+            validate_vesting_grant(&VestingGrant {
+                amount: U256::zero(),
+                ..vesting_grant.clone()
+            }),
+            Err(VestingError::InvalidVestingGrant)
+        ));
+
+        // Milestones are attested per vesting type
+        assert!(matches!(
+            validate_vesting_grant(&VestingGrant {
+                release_curve: ReleaseCurve::Milestones,
+                ..vesting_grant.clone()
+            }),
+            Err(VestingError::InvalidVestingGrant)
+        ));
+
+        // Revocation is recorded by the contract
+        assert!(matches!(
+            validate_vesting_grant(&VestingGrant {
+                revoke_time: Some(MONTH_IN_SECONDS),
+                ..vesting_grant.clone()
+            }),
+            Err(VestingError::InvalidVestingGrant)
+        ));
+
+        // Tranche schedule without tranches
+        assert!(matches!(
+            validate_vesting_grant(&VestingGrant {
+                release_curve: ReleaseCurve::Tranches,
+                ..vesting_grant
+            }),
+            Err(VestingError::InvalidVestingTranches)
+        ));
+    }
+
+    #[test]
+    fn test_vesting_record_serialization() {
+        let vesting_grant = VestingGrant {
+            beneficiary: Key::Account(AccountHash::new([1u8; 32])),
+            funding_type: VestingType::Treasury,
+            amount: U256::from(1_000),
+            start_time: None,
+            vesting_duration: Some(Duration::seconds(YEAR_IN_SECONDS as i64)),
+            cliff_duration: None,
+            vesting_period: Duration::seconds(MONTH_IN_SECONDS as i64),
+            period_mode: PeriodMode::Fixed,
+            release_curve: ReleaseCurve::Stepwise,
+            initial_unlock_share: 0,
+            tranches: Vec::new(),
+            is_revocable: false,
+            revoke_time: None,
+        };
+
+        let vesting_record = VestingRecord {
+            version: VESTING_RECORD_VERSION,
+            vesting_info: vesting_grant.to_vesting_info(),
+            start_time: 0,
+            vesting_amount: vesting_grant.amount,
+            transferred_amount: U256::from(100),
+            vesting_tranches: Vec::new(),
+            vesting_milestones: Vec::new(),
+            vesting_status: VestingStatus {
+                vesting_type: VestingType::Treasury,
+                total_amount: vesting_grant.amount,
+                vested_amount: U256::from(100),
+                is_fully_vested: false,
+                vesting_duration: Duration::seconds(YEAR_IN_SECONDS as i64),
+                start_time: Duration::ZERO,
+                cliff_end: Duration::ZERO,
+                time_until_next_release: Duration::seconds(MONTH_IN_SECONDS as i64),
+                initial_unlock_amount: U256::zero(),
+                release_amount_per_period: U256::from(83),
+                released_amount: U256::from(100),
+                elapsed_periods: U256::one(),
+                available_for_release_amount: U256::zero(),
+                total_to_release_amount: U256::from(900),
+                is_revoked: false,
+                revoke_time: Duration::ZERO,
+                is_paused: false,
+            },
+            is_paused: false,
+            acceleration_time: None,
+        };
+
+        let bytes = vesting_record.to_bytes().unwrap();
+        assert_eq!(bytes.len(), vesting_record.serialized_length());
+
+        let (actual_vesting_record, rem) = VestingRecord::from_bytes(&bytes).unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(actual_vesting_record, vesting_record);
+
+        // Records of an unknown layout are rejected
+        let mut unknown_version_bytes = bytes;
+        unknown_version_bytes[0] = VESTING_RECORD_VERSION + 1;
+        assert!(VestingRecord::from_bytes(&unknown_version_bytes).is_err());
+
+        // Grants keep their released amount and status in the same record
+        let vesting_grant_record = VestingGrantRecord {
+            version: VESTING_GRANT_RECORD_VERSION,
+            vesting_grant,
+            transferred_amount: vesting_record.transferred_amount,
+            vesting_status: vesting_record.vesting_status,
+        };
+        let bytes = vesting_grant_record.to_bytes().unwrap();
+        assert_eq!(bytes.len(), vesting_grant_record.serialized_length());
+
+        let (actual_vesting_grant_record, rem) = VestingGrantRecord::from_bytes(&bytes).unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(actual_vesting_grant_record, vesting_grant_record);
+
+        let mut unknown_version_bytes = bytes;
+        unknown_version_bytes[0] = VESTING_GRANT_RECORD_VERSION + 1;
+        assert!(VestingGrantRecord::from_bytes(&unknown_version_bytes).is_err());
+    }
+
     #[test]
-    fn test_vesting_percentages_sum_to_100() {
-        let total_percentage: u8 = VESTING_PERCENTAGES
-            .iter()
-            .map(|&(_, percentage)| percentage)
-            .sum();
+    fn test_add_calendar_months_keeps_day_of_month() {
+        // 2024-01-31 12:00:00 UTC, clamped to the end of February then back to the 31st
+        let start_time = datetime!(2024-01-31 12:00 UTC).unix_timestamp() as u64;
+
+        assert_eq!(
+            add_calendar_months(start_time, 1),
+            Some(datetime!(2024-02-29 12:00 UTC).unix_timestamp() as u64)
+        );
+        assert_eq!(
+            add_calendar_months(start_time, 2),
+            Some(datetime!(2024-03-31 12:00 UTC).unix_timestamp() as u64)
+        );
+        assert_eq!(
+            add_calendar_months(start_time, 13),
+            Some(datetime!(2025-02-28 12:00 UTC).unix_timestamp() as u64)
+        );
+    }
+
+    #[test]
+    fn test_elapsed_calendar_months() {
+        let start_time = datetime!(2024-01-15 00:00 UTC).unix_timestamp() as u64;
+        let month_boundary = datetime!(2024-03-15 00:00 UTC).unix_timestamp() as u64;
+
+        assert_eq!(elapsed_calendar_months(start_time, start_time), 0);
+        assert_eq!(elapsed_calendar_months(start_time, month_boundary - 1), 1);
+        assert_eq!(elapsed_calendar_months(start_time, month_boundary), 2);
+        assert_eq!(
+            elapsed_calendar_months(
+                start_time,
+                datetime!(2028-01-15 00:00 UTC).unix_timestamp() as u64
+            ),
+            48
+        );
+    }
+
+    #[test]
+    fn test_calendar_months_in_period() {
+        assert_eq!(
+            calendar_months_in_period(Duration::seconds(MONTH_IN_SECONDS as i64)),
+            1
+        );
         assert_eq!(
-            total_percentage, 100,
-            "VESTING_PERCENTAGES does not sum to 100%, actual total: {}%",
-            total_percentage
+            calendar_months_in_period(Duration::seconds(3 * MONTH_IN_SECONDS as i64)),
+            3
         );
+        assert_eq!(calendar_months_in_period(Duration::seconds(1)), 1);
     }
 }
@@ -0,0 +1,222 @@
+use crate::utility::{
+    constants::ACCOUNT_USER_1,
+    installer_request_builders::{
+        cowl_cep18_token_transfer, cowl_vesting_release_milestone, setup_with_args, TestContext,
+    },
+    support::{assert_expected_error, get_account_for_vesting, get_event, get_vesting_record},
+};
+use casper_engine_test_support::{InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use cowl_vesting::{
+    constants::{ARG_VESTING_ALLOCATIONS, BASIS_POINTS_DENOMINATOR, DURATION_DEVELOPMENT_VESTING},
+    enums::{ReleaseCurve, VestingType},
+    error::VestingError,
+    events::MilestoneRelease,
+    vesting::{get_default_vesting_configs, VestingMilestone, VestingStatus},
+};
+
+const MILESTONE_MAINNET: &str = "mainnet";
+const MILESTONE_AUDIT: &str = "audit";
+
+fn setup_with_milestones(vesting_type: VestingType) -> (InMemoryWasmTestBuilder, TestContext) {
+    let mut vesting_configs = get_default_vesting_configs();
+    for vesting_config in vesting_configs.iter_mut() {
+        if vesting_config.vesting_type == vesting_type {
+            // Milestones unlock on attestation, without waiting for the type cliff
+            vesting_config.cliff_duration = None;
+            vesting_config.release_curve = ReleaseCurve::Milestones;
+            vesting_config.milestones = vec![
+                VestingMilestone {
+                    name: MILESTONE_MAINNET.to_string(),
+                    share: 4_000,
+                    released_at: None,
+                },
+                VestingMilestone {
+                    name: MILESTONE_AUDIT.to_string(),
+                    share: 6_000,
+                    released_at: None,
+                },
+            ];
+        }
+    }
+
+    setup_with_args(
+        runtime_args! {
+            ARG_VESTING_ALLOCATIONS => vesting_configs,
+        },
+        None,
+    )
+}
+
+#[test]
+fn should_vest_milestone_share_once_released() {
+    let vesting_type = VestingType::Development;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_milestones(vesting_type);
+
+    let sender = *test_accounts
+        .get(&get_account_for_vesting(vesting_type))
+        .unwrap();
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    // Time alone does not vest a milestone schedule
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &sender,
+        U256::one(),
+        &account_user_1,
+        DURATION_DEVELOPMENT_VESTING.map(|duration| duration.whole_seconds() as u64),
+    )
+    .expect_failure();
+
+    cowl_vesting_release_milestone(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        MILESTONE_MAINNET,
+    )
+    .expect_success()
+    .commit();
+
+    let expected_event = MilestoneRelease::new(
+        Key::from(*DEFAULT_ACCOUNT_ADDR),
+        vesting_type.to_string(),
+        MILESTONE_MAINNET.to_string(),
+        4_000,
+    );
+    let actual_event: MilestoneRelease = get_event(&builder, &cowl_vesting_contract_hash.into(), 0);
+    assert_eq!(actual_event, expected_event);
+
+    let vesting_status: VestingStatus = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_status;
+
+    let expected_vested_amount =
+        vesting_status.total_amount * U256::from(4_000) / U256::from(BASIS_POINTS_DENOMINATOR);
+
+    assert!(!vesting_status.is_fully_vested);
+    assert_eq!(vesting_status.vested_amount, expected_vested_amount);
+    assert_eq!(vesting_status.elapsed_periods, U256::one());
+    assert_eq!(
+        vesting_status.available_for_release_amount,
+        expected_vested_amount
+    );
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &sender,
+        expected_vested_amount,
+        &account_user_1,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_release_milestone(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        MILESTONE_AUDIT,
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_status: VestingStatus = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_status;
+
+    assert!(vesting_status.is_fully_vested);
+    assert_eq!(vesting_status.vested_amount, vesting_status.total_amount);
+}
+
+#[test]
+fn should_not_release_milestone_twice_or_without_admin_rights() {
+    let vesting_type = VestingType::Development;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_milestones(vesting_type);
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    cowl_vesting_release_milestone(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        vesting_type,
+        MILESTONE_MAINNET,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InsufficientRights as u16,
+        "only admins can release milestones",
+    );
+
+    cowl_vesting_release_milestone(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        MILESTONE_MAINNET,
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_release_milestone(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        MILESTONE_MAINNET,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::MilestoneAlreadyReleased as u16,
+        "should not release a milestone twice",
+    );
+
+    cowl_vesting_release_milestone(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        VestingType::Treasury,
+        MILESTONE_MAINNET,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::NotMilestoneSchedule as u16,
+        "should not release a milestone of a time based schedule",
+    );
+}
@@ -0,0 +1,242 @@
+use crate::utility::{
+    constants::ACCOUNT_USER_1,
+    installer_request_builders::{
+        cowl_cep18_token_transfer, cowl_vesting_pause, cowl_vesting_unpause,
+        cowl_vesting_vesting_status, setup, TestContext,
+    },
+    support::{assert_expected_error, get_account_for_vesting, get_event, get_vesting_record},
+};
+use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
+use casper_types::{Key, U256};
+use cowl_vesting::{
+    enums::VestingType,
+    error::VestingError,
+    events::{VestingPause, VestingUnpause},
+};
+
+#[test]
+fn should_deny_vesting_transfers_of_paused_vesting_type() {
+    // Liquidity has no vesting duration and is transferable right after start time
+    let vesting_type = VestingType::Liquidity;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let vesting_account = *test_accounts
+        .get(&get_account_for_vesting(vesting_type))
+        .unwrap();
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    cowl_vesting_pause(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        Some(vesting_type),
+    )
+    .expect_success()
+    .commit();
+
+    let expected_event = VestingPause::new(
+        Key::from(*DEFAULT_ACCOUNT_ADDR),
+        Some(vesting_type.to_string()),
+    );
+    let actual_event: VestingPause = get_event(&builder, &cowl_vesting_contract_hash.into(), 0);
+    assert_eq!(actual_event, expected_event);
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &vesting_account,
+        U256::one(),
+        &account_user_1,
+        None,
+    )
+    .expect_failure();
+
+    cowl_vesting_vesting_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_record = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    );
+    assert!(vesting_record.is_paused);
+    assert!(vesting_record.vesting_status.is_paused);
+
+    // Other vesting types are not affected
+    let other_vesting_record = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        VestingType::Treasury,
+    );
+    assert!(!other_vesting_record.vesting_status.is_paused);
+
+    cowl_vesting_unpause(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        Some(vesting_type),
+    )
+    .expect_success()
+    .commit();
+
+    let expected_event = VestingUnpause::new(
+        Key::from(*DEFAULT_ACCOUNT_ADDR),
+        Some(vesting_type.to_string()),
+    );
+    let actual_event: VestingUnpause = get_event(&builder, &cowl_vesting_contract_hash.into(), 1);
+    assert_eq!(actual_event, expected_event);
+
+    let vesting_record = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    );
+    assert!(!vesting_record.is_paused);
+    assert!(!vesting_record.vesting_status.is_paused);
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &vesting_account,
+        vesting_record.vesting_amount,
+        &account_user_1,
+        None,
+    )
+    .expect_success()
+    .commit();
+}
+
+#[test]
+fn should_pause_all_vesting_types_by_admin_only() {
+    let vesting_type = VestingType::Liquidity;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let vesting_account = *test_accounts
+        .get(&get_account_for_vesting(vesting_type))
+        .unwrap();
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    cowl_vesting_pause(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        None,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InsufficientRights as u16,
+        "only admins can pause",
+    );
+
+    cowl_vesting_pause(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    let expected_event = VestingPause::new(Key::from(*DEFAULT_ACCOUNT_ADDR), None);
+    let actual_event: VestingPause = get_event(&builder, &cowl_vesting_contract_hash.into(), 0);
+    assert_eq!(actual_event, expected_event);
+
+    for vesting_type in [VestingType::Liquidity, VestingType::Treasury] {
+        let vesting_record = get_vesting_record(
+            &builder,
+            &Key::from(cowl_vesting_contract_hash),
+            vesting_type,
+        );
+        // The pause of all vesting types is kept apart from the pause of each type
+        assert!(!vesting_record.is_paused);
+        assert!(vesting_record.vesting_status.is_paused);
+    }
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &vesting_account,
+        U256::one(),
+        &account_user_1,
+        None,
+    )
+    .expect_failure();
+
+    cowl_vesting_pause(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        None,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::VestingAlreadyPaused as u16,
+        "should not pause twice",
+    );
+
+    cowl_vesting_unpause(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_unpause(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        None,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::VestingNotPaused as u16,
+        "should not unpause when not paused",
+    );
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &vesting_account,
+        U256::one(),
+        &account_user_1,
+        None,
+    )
+    .expect_success()
+    .commit();
+}
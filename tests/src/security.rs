@@ -1,6 +1,10 @@
+use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
 use casper_types::{runtime_args, Key, RuntimeArgs};
 use cowl_vesting::{
-    constants::ADMIN_LIST, enums::EventsMode, error::VestingError, events::ChangeSecurity,
+    constants::{ADMIN_LIST, ADMIN_PROPOSAL_DURATION_IN_SECONDS},
+    enums::{EventsMode, VestingType},
+    error::VestingError,
+    events::{AdminAcceptance, AdminProposal, AdminProposalCancellation, ChangeSecurity},
     security::SecurityBadge,
 };
 
@@ -11,10 +15,12 @@ use crate::{
     utility::{
         constants::{ACCOUNT_USER_1, ACCOUNT_USER_2},
         installer_request_builders::{
-            cowl_vesting_change_security, cowl_vesting_set_modalities, setup, setup_with_args,
-            SecurityLists, TestContext,
+            cowl_vesting_accept_admin, cowl_vesting_add_vesting_address,
+            cowl_vesting_cancel_admin_proposal, cowl_vesting_change_security, cowl_vesting_pause,
+            cowl_vesting_propose_admin, cowl_vesting_set_modalities, cowl_vesting_set_start_time,
+            setup, setup_with_args, SecurityLists, TestContext,
         },
-        support::{assert_expected_error, create_dummy_key_pair, fund_account},
+        support::{assert_expected_error, create_dummy_key_pair, fund_account, get_admin_count},
     },
 };
 
@@ -126,6 +132,7 @@ fn should_test_change_security() {
     let security_lists = SecurityLists {
         admin_list: Some(vec![Key::Account(account_user_2)]),
         none_list: None,
+        ..Default::default()
     };
 
     let change_security = cowl_vesting_change_security(
@@ -135,23 +142,35 @@ fn should_test_change_security() {
         security_lists,
     );
 
-    change_security.expect_success().commit();
+    change_security.expect_failure();
 
-    let expected_event = ChangeSecurity {
-        admin: Key::Account(account_user_1),
-        sec_change_map: {
-            let mut map = BTreeMap::new();
-            map.insert(Key::Account(account_user_2), SecurityBadge::Admin);
-            map
-        },
-    };
-    let actual_event: ChangeSecurity = get_event(&builder, &cowl_vesting_contract_hash.into(), 0);
+    let error = builder.get_error().expect("must have error");
 
-    assert_eq!(
-        actual_event, expected_event,
-        "Expected ChangeSecurity event."
+    assert_expected_error(
+        error,
+        VestingError::AdminGrantRequiresProposal as u16,
+        "should only add admins through a proposal",
     );
 
+    cowl_vesting_propose_admin(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        Key::Account(account_user_2),
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_accept_admin(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_2,
+        None,
+    )
+    .expect_success()
+    .commit();
+
     let owner = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
     let set_modalities_call = cowl_vesting_set_modalities(
         &mut builder,
@@ -173,6 +192,7 @@ fn should_test_change_security() {
     let security_lists = SecurityLists {
         admin_list: None,
         none_list: Some(vec![Key::Account(account_user_2)]),
+        ..Default::default()
     };
 
     let change_security = cowl_vesting_change_security(
@@ -201,3 +221,377 @@ fn should_test_change_security() {
         "should not allow to mint for non default admin account",
     );
 }
+
+#[test]
+fn should_keep_admin_count_on_change_security() {
+    let (_, public_key_account_user_1) = create_dummy_key_pair(ACCOUNT_USER_1);
+    let account_user_1 = public_key_account_user_1.to_account_hash();
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ADMIN_LIST => vec![Key::from(account_user_1)],
+        },
+        None,
+    );
+
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let contract_key = Key::from(cowl_vesting_contract_hash);
+
+    assert_eq!(get_admin_count(&builder, &contract_key), 1);
+
+    cowl_vesting_propose_admin(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        Key::Account(account_user_2),
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_accept_admin(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_2,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    assert_eq!(get_admin_count(&builder, &contract_key), 2);
+
+    // Moving an admin to another role takes it out of the count
+    cowl_vesting_change_security(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        SecurityLists {
+            pauser_list: Some(vec![Key::Account(account_user_2)]),
+            ..Default::default()
+        },
+    )
+    .expect_success()
+    .commit();
+
+    assert_eq!(get_admin_count(&builder, &contract_key), 1);
+}
+
+#[test]
+fn should_grant_admin_once_proposal_is_accepted() {
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let proposal_time = 1_000;
+    let expiry_time = proposal_time + ADMIN_PROPOSAL_DURATION_IN_SECONDS;
+
+    cowl_vesting_propose_admin(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        Key::from(account_user_1),
+        Some(proposal_time),
+    )
+    .expect_success()
+    .commit();
+
+    let expected_event = AdminProposal::new(
+        Key::from(*DEFAULT_ACCOUNT_ADDR),
+        Key::from(account_user_1),
+        expiry_time,
+    );
+    let actual_event: AdminProposal = get_event(&builder, &cowl_vesting_contract_hash.into(), 0);
+    assert_eq!(actual_event, expected_event);
+
+    // The nominee is no admin until it accepts
+    cowl_vesting_set_modalities(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        Some(EventsMode::CES),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InsufficientRights as u16,
+        "should not allow a pending admin to set_modalities",
+    );
+
+    cowl_vesting_accept_admin(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_2,
+        Some(proposal_time),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::MissingAdminProposal as u16,
+        "should not allow a key that was not nominated to accept",
+    );
+
+    cowl_vesting_accept_admin(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        Some(expiry_time),
+    )
+    .expect_success()
+    .commit();
+
+    let expected_event = AdminAcceptance::new(Key::from(account_user_1));
+    let actual_event: AdminAcceptance = get_event(&builder, &cowl_vesting_contract_hash.into(), 1);
+    assert_eq!(actual_event, expected_event);
+
+    assert_eq!(
+        get_admin_count(&builder, &Key::from(cowl_vesting_contract_hash)),
+        2
+    );
+
+    cowl_vesting_set_modalities(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        Some(EventsMode::CES),
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_propose_admin(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        Key::from(account_user_1),
+        None,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::AlreadyAdmin as u16,
+        "should not nominate a key that already is admin",
+    );
+}
+
+#[test]
+fn should_not_accept_expired_or_cancelled_admin_proposal() {
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let proposal_time = 1_000;
+
+    cowl_vesting_propose_admin(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        Key::from(account_user_1),
+        Some(proposal_time),
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_accept_admin(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        Some(proposal_time + ADMIN_PROPOSAL_DURATION_IN_SECONDS + 1),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::AdminProposalExpired as u16,
+        "should not accept an expired admin proposal",
+    );
+
+    cowl_vesting_cancel_admin_proposal(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        Key::from(account_user_1),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InsufficientRights as u16,
+        "only admins can cancel admin proposals",
+    );
+
+    cowl_vesting_cancel_admin_proposal(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        Key::from(account_user_1),
+    )
+    .expect_success()
+    .commit();
+
+    let expected_event =
+        AdminProposalCancellation::new(Key::from(*DEFAULT_ACCOUNT_ADDR), Key::from(account_user_1));
+    let actual_event: AdminProposalCancellation =
+        get_event(&builder, &cowl_vesting_contract_hash.into(), 1);
+    assert_eq!(actual_event, expected_event);
+
+    cowl_vesting_accept_admin(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        Some(proposal_time),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::MissingAdminProposal as u16,
+        "should not accept a cancelled admin proposal",
+    );
+}
+
+#[test]
+fn should_restrict_entry_points_to_their_roles() {
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let account_pauser = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_schedule_manager = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+
+    cowl_vesting_change_security(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        SecurityLists {
+            pauser_list: Some(vec![Key::Account(account_pauser)]),
+            schedule_manager_list: Some(vec![Key::Account(account_schedule_manager)]),
+            ..Default::default()
+        },
+    )
+    .expect_success()
+    .commit();
+
+    let expected_event = ChangeSecurity::new(Key::from(*DEFAULT_ACCOUNT_ADDR), {
+        let mut map = BTreeMap::new();
+        map.insert(Key::Account(account_pauser), SecurityBadge::Pauser);
+        map.insert(
+            Key::Account(account_schedule_manager),
+            SecurityBadge::ScheduleManager,
+        );
+        map
+    });
+    let actual_event: ChangeSecurity = get_event(&builder, &cowl_vesting_contract_hash.into(), 0);
+    assert_eq!(actual_event, expected_event);
+
+    // Roles do not add up to admin rights
+    assert_eq!(
+        get_admin_count(&builder, &Key::from(cowl_vesting_contract_hash)),
+        1
+    );
+
+    cowl_vesting_pause(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_schedule_manager,
+        None,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InsufficientRights as u16,
+        "only pausers and admins can pause",
+    );
+
+    cowl_vesting_pause(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_pauser,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_set_start_time(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_pauser,
+        VestingType::Treasury,
+        0,
+        None,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InsufficientRights as u16,
+        "only schedule managers and admins can set start time",
+    );
+
+    // Schedule managers only move schedule parameters, vesting addresses stay with admins
+    cowl_vesting_add_vesting_address(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_schedule_manager,
+        VestingType::Treasury,
+        Key::Account(account_schedule_manager),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InsufficientRights as u16,
+        "only admins can add vesting addresses",
+    );
+
+    cowl_vesting_set_modalities(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_pauser,
+        Some(EventsMode::NoEvents),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InsufficientRights as u16,
+        "only admins can set_modalities",
+    );
+}
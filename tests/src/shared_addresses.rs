@@ -0,0 +1,255 @@
+use crate::utility::{
+    constants::{ACCOUNT_USER_1, ACCOUNT_USER_2},
+    installer_request_builders::{
+        cowl_cep18_token_transfer, cowl_vesting_add_vesting_address,
+        cowl_vesting_remove_vesting_address, setup, TestContext,
+    },
+    support::{assert_expected_error, get_account_for_vesting, get_event, get_vesting_record},
+};
+use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
+use casper_types::{Key, U256};
+use cowl_vesting::{
+    constants::MONTH_IN_SECONDS,
+    enums::VestingType,
+    error::VestingError,
+    events::{VestingAddressAddition, VestingAddressRemoval},
+};
+
+#[test]
+fn should_cap_combined_outflow_of_addresses_sharing_a_schedule() {
+    let vesting_type = VestingType::Community;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let vesting_account = *test_accounts
+        .get(&get_account_for_vesting(vesting_type))
+        .unwrap();
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+
+    let vesting_amount: U256 = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_amount;
+
+    cowl_vesting_add_vesting_address(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Key::from(account_user_1),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_address_keys = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_info
+    .vesting_address_keys;
+    assert_eq!(
+        vesting_address_keys,
+        vec![Key::from(vesting_account), Key::from(account_user_1)]
+    );
+
+    let expected_event = VestingAddressAddition::new(
+        Key::from(*DEFAULT_ACCOUNT_ADDR),
+        vesting_type.to_string(),
+        Key::from(account_user_1),
+    );
+    let actual_event: VestingAddressAddition =
+        get_event(&builder, &cowl_vesting_contract_hash.into(), 0);
+    assert_eq!(actual_event, expected_event);
+
+    // Moves between addresses sharing the schedule are not counted
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &vesting_account,
+        vesting_amount,
+        &account_user_1,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    // The shared address is locked by the same schedule
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &account_user_1,
+        vesting_amount,
+        &account_user_2,
+        Some(MONTH_IN_SECONDS),
+    )
+    .expect_failure();
+
+    // A shared address holding tokens can not leave the schedule
+    cowl_vesting_remove_vesting_address(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Key::from(account_user_1),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::VestingAddressNotEmpty as u16,
+        "should not remove a shared address holding tokens",
+    );
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &account_user_1,
+        vesting_amount,
+        &vesting_account,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_remove_vesting_address(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Key::from(account_user_1),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_address_keys = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_info
+    .vesting_address_keys;
+    assert_eq!(vesting_address_keys, vec![Key::from(vesting_account)]);
+
+    // Each member transfer records a CheckTransfer event
+    let expected_event = VestingAddressRemoval::new(
+        Key::from(*DEFAULT_ACCOUNT_ADDR),
+        vesting_type.to_string(),
+        Key::from(account_user_1),
+    );
+    let actual_event: VestingAddressRemoval =
+        get_event(&builder, &cowl_vesting_contract_hash.into(), 3);
+    assert_eq!(actual_event, expected_event);
+}
+
+#[test]
+fn should_not_add_vesting_address_without_admin_rights_or_holding_a_schedule_or_tokens() {
+    let vesting_type = VestingType::Community;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+    let treasury_account = *test_accounts
+        .get(&get_account_for_vesting(VestingType::Treasury))
+        .unwrap();
+    let liquidity_account = *test_accounts
+        .get(&get_account_for_vesting(VestingType::Liquidity))
+        .unwrap();
+
+    cowl_vesting_add_vesting_address(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        vesting_type,
+        Key::from(account_user_1),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InsufficientRights as u16,
+        "only admins can add a vesting address",
+    );
+
+    cowl_vesting_add_vesting_address(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Key::from(treasury_account),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InvalidVestingAddress as u16,
+        "should not share a schedule with an address holding one",
+    );
+
+    // Liquidity has no vesting duration and is transferable right after start time
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &liquidity_account,
+        U256::one(),
+        &account_user_2,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_add_vesting_address(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Key::from(account_user_2),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::VestingAddressNotEmpty as u16,
+        "should not bring the tokens of an address under a schedule",
+    );
+
+    cowl_vesting_remove_vesting_address(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Key::from(account_user_1),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InvalidVestingAddress as u16,
+        "should not remove an address not sharing the schedule",
+    );
+}
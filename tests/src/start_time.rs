@@ -0,0 +1,199 @@
+use crate::utility::{
+    constants::ACCOUNT_USER_1,
+    installer_request_builders::{
+        cowl_cep18_token_transfer, cowl_vesting_set_start_time, cowl_vesting_vesting_status, setup,
+        setup_with_args, TestContext,
+    },
+    support::{assert_expected_error, get_account_for_vesting, get_event, get_vesting_record},
+};
+use casper_engine_test_support::{InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
+use cowl_vesting::{
+    constants::{ARG_VESTING_ALLOCATIONS, MONTH_IN_SECONDS, YEAR_IN_SECONDS},
+    enums::VestingType,
+    error::VestingError,
+    events::StartTimeUpdate,
+    vesting::{get_default_vesting_configs, VestingStatus},
+};
+use std::time::Duration;
+
+fn setup_with_start_time(
+    vesting_type: VestingType,
+    start_time: u64,
+) -> (InMemoryWasmTestBuilder, TestContext) {
+    let mut vesting_configs = get_default_vesting_configs();
+    for vesting_config in vesting_configs.iter_mut() {
+        if vesting_config.vesting_type == vesting_type {
+            vesting_config.start_time = Some(start_time);
+        }
+    }
+
+    setup_with_args(
+        runtime_args! {
+            ARG_VESTING_ALLOCATIONS => vesting_configs,
+        },
+        None,
+    )
+}
+
+#[test]
+fn should_lock_vesting_transfers_before_start_time() {
+    // Liquidity has no vesting duration and is transferable right after start time
+    let vesting_type = VestingType::Liquidity;
+    let start_time = YEAR_IN_SECONDS;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_start_time(vesting_type, start_time);
+
+    cowl_vesting_vesting_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Some(MONTH_IN_SECONDS),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_status: VestingStatus = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_status;
+
+    assert!(!vesting_status.is_fully_vested);
+    assert_eq!(vesting_status.vested_amount, U256::zero());
+    assert_eq!(vesting_status.available_for_release_amount, U256::zero());
+    assert_eq!(vesting_status.start_time, Duration::from_secs(start_time));
+    assert_eq!(
+        vesting_status.time_until_next_release,
+        Duration::from_secs(start_time - MONTH_IN_SECONDS)
+    );
+
+    let sender = *test_accounts
+        .get(&get_account_for_vesting(vesting_type))
+        .unwrap();
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &sender,
+        U256::one(),
+        &account_user_1,
+        Some(MONTH_IN_SECONDS),
+    )
+    .expect_failure();
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &sender,
+        vesting_status.total_amount,
+        &account_user_1,
+        Some(start_time),
+    )
+    .expect_success()
+    .commit();
+}
+
+#[test]
+fn should_move_start_time_before_vesting_started() {
+    let vesting_type = VestingType::Community;
+    let start_time = MONTH_IN_SECONDS;
+    let new_start_time = YEAR_IN_SECONDS;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup_with_start_time(vesting_type, start_time);
+
+    // Only admins can move the start time
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    cowl_vesting_set_start_time(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        vesting_type,
+        new_start_time,
+        None,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InsufficientRights as u16,
+        "only admins can set the start time",
+    );
+
+    cowl_vesting_set_start_time(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        new_start_time,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    let actual_start_time: u64 = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .start_time;
+    assert_eq!(actual_start_time, new_start_time);
+
+    let expected_event = StartTimeUpdate::new(
+        Key::from(*DEFAULT_ACCOUNT_ADDR),
+        vesting_type.to_string(),
+        start_time,
+        new_start_time,
+    );
+    let actual_event: StartTimeUpdate = get_event(&builder, &cowl_vesting_contract_hash.into(), 0);
+    assert_eq!(actual_event, expected_event);
+}
+
+#[test]
+fn should_not_move_start_time_once_vesting_started() {
+    let vesting_type = VestingType::Community;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ..
+        },
+    ) = setup();
+
+    cowl_vesting_set_start_time(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        YEAR_IN_SECONDS,
+        Some(MONTH_IN_SECONDS),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::VestingAlreadyStarted as u16,
+        "should not move the start time of a started vesting",
+    );
+}
@@ -3,14 +3,16 @@ use crate::utility::{
         VESTING_CONTRACT_KEY_NAME, VESTING_CONTRACT_VERSION, VESTING_CONTRACT_WASM,
         VESTING_TEST_NAME,
     },
-    installer_request_builders::{setup, TestContext},
-    support::get_event,
+    installer_request_builders::{cowl_vesting_pause, setup, TestContext},
+    support::{get_dictionary_value_from_key, get_event},
 };
 use casper_engine_test_support::{ExecuteRequestBuilder, DEFAULT_ACCOUNT_ADDR};
-use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs};
+use casper_event_standard::{try_full_name_from_bytes, Schemas, EVENTS_DICT, EVENTS_SCHEMA};
+use casper_types::{bytesrepr::Bytes, runtime_args, ContractHash, Key, RuntimeArgs};
 use cowl_vesting::{
     constants::{ARG_CONTRACT_HASH, ARG_NAME, ARG_UPGRADE_FLAG},
-    events::Upgrade,
+    enums::VestingType,
+    events::{get_event_schemas, Upgrade, VestingPause},
 };
 
 #[test]
@@ -139,3 +141,80 @@ fn should_emit_event_on_upgrade_with_events_mode_ces() {
     let actual_event: Upgrade = get_event(&builder, &cowl_vesting_contract_hash.into(), 0);
     assert_eq!(actual_event, expected_event, "Expected Upgrade event.");
 }
+
+#[test]
+fn should_describe_events_added_since_install_after_upgrade() {
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ..
+        },
+    ) = setup();
+
+    let upgrade_request_contract = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        VESTING_CONTRACT_WASM,
+        runtime_args! {
+            ARG_UPGRADE_FLAG => true,
+            ARG_NAME => VESTING_TEST_NAME,
+        },
+    )
+    .build();
+    builder
+        .exec(upgrade_request_contract)
+        .expect_success()
+        .commit();
+
+    let upgraded_cowl_vesting: ContractHash = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(VESTING_CONTRACT_KEY_NAME)
+        .unwrap()
+        .into_hash()
+        .unwrap()
+        .into();
+
+    // The stored schemas are rewritten on upgrade
+    let schemas: Schemas = builder
+        .query(
+            None,
+            Key::from(upgraded_cowl_vesting),
+            &[EVENTS_SCHEMA.to_string()],
+        )
+        .unwrap()
+        .as_cl_value()
+        .unwrap()
+        .to_owned()
+        .into_t()
+        .unwrap();
+    assert_eq!(schemas, get_event_schemas());
+
+    cowl_vesting_pause(
+        &mut builder,
+        &upgraded_cowl_vesting,
+        &DEFAULT_ACCOUNT_ADDR,
+        Some(VestingType::Treasury),
+    )
+    .expect_success()
+    .commit();
+
+    // The first event is the upgrade
+    let event_bytes: Bytes = get_dictionary_value_from_key(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        EVENTS_DICT,
+        "1",
+    );
+    let event_name = try_full_name_from_bytes(&event_bytes).unwrap();
+    let schema_name = event_name.strip_prefix("event_").unwrap();
+    assert!(schemas.0.contains_key(schema_name));
+
+    let expected_event = VestingPause::new(
+        Key::from(*DEFAULT_ACCOUNT_ADDR),
+        Some(VestingType::Treasury.to_string()),
+    );
+    let actual_event: VestingPause = get_event(&builder, &cowl_vesting_contract_hash.into(), 1);
+    assert_eq!(actual_event, expected_event);
+}
@@ -11,17 +11,31 @@ use casper_engine_test_support::{
     PRODUCTION_RUN_GENESIS_REQUEST,
 };
 use casper_types::{
-    account::AccountHash, runtime_args, ContractHash, ContractPackageHash, Key, RuntimeArgs, U256,
+    account::AccountHash, bytesrepr::Bytes, runtime_args, ContractHash, ContractPackageHash, Key,
+    RuntimeArgs, U256,
 };
 use cowl_vesting::{
     constants::{
-        ADMIN_LIST, ARG_COWL_CEP18_CONTRACT_PACKAGE, ARG_ENABLE_MINT_BURN, ARG_EVENTS_MODE,
-        ARG_NAME, ARG_RECIPIENT, ARG_TOTAL_SUPPLY, ARG_TRANSFER_FILTER_CONTRACT_PACKAGE,
-        ARG_TRANSFER_FILTER_METHOD, ARG_VESTING_TYPE, ENTRY_POINT_CHANGE_SECURITY,
-        ENTRY_POINT_CHECK_VESTING_TRANSFER, ENTRY_POINT_SET_MODALITIES, ENTRY_POINT_TRANSFER,
-        ENTRY_POINT_VESTING_INFO, ENTRY_POINT_VESTING_STATUS, NONE_LIST,
+        ADMIN_LIST, ARG_ACCELERATION_TIME, ARG_ADMIN, ARG_BENEFICIARIES, ARG_BENEFICIARY,
+        ARG_CLIFF_DURATION, ARG_COWL_CEP18_CONTRACT_PACKAGE, ARG_DATA, ARG_ENABLE_MINT_BURN,
+        ARG_EVENTS_MODE, ARG_FROM, ARG_MILESTONE_NAME, ARG_NAME, ARG_OPERATOR, ARG_RECIPIENT,
+        ARG_SPENDER, ARG_START_TIME, ARG_TO, ARG_TOTAL_SUPPLY,
+        ARG_TRANSFER_FILTER_CONTRACT_PACKAGE, ARG_TRANSFER_FILTER_METHOD, ARG_VESTING_ADDRESS,
+        ARG_VESTING_GRANT, ARG_VESTING_MODE, ARG_VESTING_TYPE, ARG_VESTING_TYPES,
+        ENTRY_POINT_ACCELERATE, ENTRY_POINT_ACCEPT_ADMIN, ENTRY_POINT_ACCEPT_VESTING_ADDRESS,
+        ENTRY_POINT_ADD_VESTING_ADDRESS, ENTRY_POINT_CANCEL_ADMIN_PROPOSAL,
+        ENTRY_POINT_CHANGE_SECURITY, ENTRY_POINT_CHANGE_VESTING_ADDRESS,
+        ENTRY_POINT_CHECK_VESTING_TRANSFER, ENTRY_POINT_CLAIM,
+        ENTRY_POINT_CREATE_CONTRIBUTOR_GRANT, ENTRY_POINT_CREATE_GRANT,
+        ENTRY_POINT_INCREASE_ALLOWANCE, ENTRY_POINT_PAUSE, ENTRY_POINT_PREVIEW_VESTING_TRANSFER,
+        ENTRY_POINT_PROPOSE_ADMIN, ENTRY_POINT_RELEASE_DUE, ENTRY_POINT_RELEASE_MILESTONE,
+        ENTRY_POINT_REMOVE_VESTING_ADDRESS, ENTRY_POINT_REVOKE, ENTRY_POINT_SET_MODALITIES,
+        ENTRY_POINT_SET_START_TIME, ENTRY_POINT_TRANSFER, ENTRY_POINT_UNPAUSE,
+        ENTRY_POINT_VESTING_INFO, ENTRY_POINT_VESTING_STATUS, KEEPER_LIST, NONE_LIST, PAUSER_LIST,
+        SCHEDULE_MANAGER_LIST,
     },
-    enums::{EventsMode, VestingType},
+    enums::{EventsMode, VestingMode, VestingType},
+    vesting::VestingGrant,
 };
 use std::collections::HashMap;
 
@@ -130,6 +144,12 @@ pub(crate) fn setup_with_args(
         test_accounts.insert(account, account_key);
     }
 
+    // Custody installs keep the allocations and set no transfer filter
+    let vesting_mode = install_args
+        .get(ARG_VESTING_MODE)
+        .map(|vesting_mode| vesting_mode.clone().into_t::<u8>().unwrap())
+        .unwrap_or_default();
+
     // Install vesting contract with token
     let install_request_contract = ExecuteRequestBuilder::standard(
         *DEFAULT_ACCOUNT_ADDR,
@@ -173,25 +193,27 @@ pub(crate) fn setup_with_args(
         cowl_cep18_token_package_hash
     );
 
-    // Check vesting contract as filter contract has been updated in token contract
-    let actual_transfer_contract_package: ContractPackageHash = builder
-        .get_value::<Option<ContractPackageHash>>(
-            cowl_cep18_token_contract_hash,
-            ARG_TRANSFER_FILTER_CONTRACT_PACKAGE,
-        )
-        .unwrap();
-
-    assert_eq!(
-        actual_transfer_contract_package,
-        cowl_vesting_contract_package_hash
-    );
+    if vesting_mode != VestingMode::Custody as u8 {
+        // Check vesting contract as filter contract has been updated in token contract
+        let actual_transfer_contract_package: ContractPackageHash = builder
+            .get_value::<Option<ContractPackageHash>>(
+                cowl_cep18_token_contract_hash,
+                ARG_TRANSFER_FILTER_CONTRACT_PACKAGE,
+            )
+            .unwrap();
+
+        assert_eq!(
+            actual_transfer_contract_package,
+            cowl_vesting_contract_package_hash
+        );
 
-    // Check filter method has been updated in token contract
-    let actual_transfer_method: String = builder
-        .get_value::<Option<String>>(cowl_cep18_token_contract_hash, ARG_TRANSFER_FILTER_METHOD)
-        .unwrap();
+        // Check filter method has been updated in token contract
+        let actual_transfer_method: String = builder
+            .get_value::<Option<String>>(cowl_cep18_token_contract_hash, ARG_TRANSFER_FILTER_METHOD)
+            .unwrap();
 
-    assert_eq!(actual_transfer_method, ENTRY_POINT_CHECK_VESTING_TRANSFER);
+        assert_eq!(actual_transfer_method, ENTRY_POINT_CHECK_VESTING_TRANSFER);
+    }
 
     let test_context = TestContext {
         cowl_vesting_contract_hash,
@@ -295,8 +317,407 @@ pub fn cowl_cep18_token_transfer<'a>(
     builder.exec(token_transfer_request.build())
 }
 
+pub fn cowl_vesting_set_start_time<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    vesting_type: VestingType,
+    start_time: u64,
+    block_time: Option<u64>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let args = runtime_args! {
+        ARG_VESTING_TYPE => vesting_type.to_string(),
+        ARG_START_TIME => start_time,
+    };
+
+    let mut set_start_time_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_SET_START_TIME,
+        args,
+    );
+
+    if let Some(block_time) = block_time {
+        set_start_time_request = set_start_time_request.with_block_time(block_time * 1000)
+    }
+
+    builder.exec(set_start_time_request.build())
+}
+
+pub fn cowl_vesting_release_milestone<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    vesting_type: VestingType,
+    milestone_name: &str,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let release_milestone_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_RELEASE_MILESTONE,
+        runtime_args! {
+            ARG_VESTING_TYPE => vesting_type.to_string(),
+            ARG_MILESTONE_NAME => milestone_name,
+        },
+    )
+    .build();
+    builder.exec(release_milestone_request)
+}
+
+pub fn cowl_vesting_create_grant<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    vesting_grant: VestingGrant,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let create_grant_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_CREATE_GRANT,
+        runtime_args! {
+            ARG_VESTING_GRANT => vesting_grant,
+        },
+    )
+    .build();
+    builder.exec(create_grant_request)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn cowl_vesting_create_contributor_grant<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    beneficiary: Key,
+    amount: U256,
+    start_time: Option<u64>,
+    cliff_duration: Option<u64>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let create_contributor_grant_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_CREATE_CONTRIBUTOR_GRANT,
+        runtime_args! {
+            ARG_BENEFICIARY => beneficiary,
+            ARG_AMOUNT => amount,
+            ARG_START_TIME => start_time,
+            ARG_CLIFF_DURATION => cliff_duration,
+        },
+    )
+    .build();
+    builder.exec(create_contributor_grant_request)
+}
+
+pub fn cowl_vesting_pause<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    vesting_type: Option<VestingType>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let pause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_PAUSE,
+        runtime_args! {
+            ARG_VESTING_TYPE => vesting_type.map(|vesting_type| vesting_type.to_string()),
+        },
+    )
+    .build();
+    builder.exec(pause_request)
+}
+
+pub fn cowl_vesting_accelerate<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    vesting_type: Option<VestingType>,
+    acceleration_time: Option<u64>,
+    block_time: Option<u64>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let mut accelerate_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_ACCELERATE,
+        runtime_args! {
+            ARG_VESTING_TYPE => vesting_type.map(|vesting_type| vesting_type.to_string()),
+            ARG_ACCELERATION_TIME => acceleration_time,
+        },
+    );
+
+    if let Some(block_time) = block_time {
+        accelerate_request = accelerate_request.with_block_time(block_time * 1000)
+    }
+
+    builder.exec(accelerate_request.build())
+}
+
+pub fn cowl_vesting_unpause<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    vesting_type: Option<VestingType>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let unpause_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_UNPAUSE,
+        runtime_args! {
+            ARG_VESTING_TYPE => vesting_type.map(|vesting_type| vesting_type.to_string()),
+        },
+    )
+    .build();
+    builder.exec(unpause_request)
+}
+
+pub fn cowl_vesting_revoke<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    beneficiary: Key,
+    block_time: Option<u64>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let mut revoke_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_REVOKE,
+        runtime_args! {
+            ARG_BENEFICIARY => beneficiary,
+        },
+    );
+
+    if let Some(block_time) = block_time {
+        revoke_request = revoke_request.with_block_time(block_time * 1000)
+    }
+
+    builder.exec(revoke_request.build())
+}
+
+pub fn cowl_vesting_grant_status<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    beneficiary: Key,
+    block_time: Option<u64>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let args = runtime_args! {
+        ARG_BENEFICIARY => Some(beneficiary)
+    };
+
+    let mut grant_status_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_VESTING_STATUS,
+        args,
+    );
+
+    if let Some(block_time) = block_time {
+        grant_status_request = grant_status_request.with_block_time(block_time * 1000)
+    }
+
+    builder.exec(grant_status_request.build())
+}
+
+pub fn cowl_vesting_change_vesting_address<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    vesting_type: VestingType,
+    vesting_address_key: Key,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let change_vesting_address_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_CHANGE_VESTING_ADDRESS,
+        runtime_args! {
+            ARG_VESTING_TYPE => vesting_type.to_string(),
+            ARG_VESTING_ADDRESS => vesting_address_key,
+        },
+    )
+    .build();
+    builder.exec(change_vesting_address_request)
+}
+
+pub fn cowl_vesting_accept_vesting_address<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    vesting_type: VestingType,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let accept_vesting_address_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_ACCEPT_VESTING_ADDRESS,
+        runtime_args! {
+            ARG_VESTING_TYPE => vesting_type.to_string(),
+        },
+    )
+    .build();
+    builder.exec(accept_vesting_address_request)
+}
+
+pub fn cowl_vesting_check_vesting_transfer<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    from: &AccountHash,
+    to: &AccountHash,
+    amount: U256,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let check_vesting_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_CHECK_VESTING_TRANSFER,
+        runtime_args! {
+            ARG_OPERATOR => Key::Account(*sender),
+            ARG_FROM => Key::Account(*from),
+            ARG_TO => Key::Account(*to),
+            ARG_AMOUNT => amount,
+            ARG_DATA => Option::<Bytes>::None,
+        },
+    )
+    .build();
+    builder.exec(check_vesting_transfer_request)
+}
+
+pub fn cowl_vesting_preview_vesting_transfer<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    from: &AccountHash,
+    to: &AccountHash,
+    amount: U256,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let preview_vesting_transfer_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_PREVIEW_VESTING_TRANSFER,
+        runtime_args! {
+            ARG_FROM => Key::Account(*from),
+            ARG_TO => Key::Account(*to),
+            ARG_AMOUNT => amount,
+        },
+    )
+    .build();
+    builder.exec(preview_vesting_transfer_request)
+}
+
+pub fn cowl_vesting_claim<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    block_time: Option<u64>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let mut claim_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_CLAIM,
+        runtime_args! {},
+    );
+
+    if let Some(block_time) = block_time {
+        claim_request = claim_request.with_block_time(block_time * 1000)
+    }
+
+    builder.exec(claim_request.build())
+}
+
+pub fn cowl_vesting_release_due<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    vesting_types: Option<Vec<VestingType>>,
+    beneficiaries: Option<Vec<Key>>,
+    block_time: Option<u64>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let vesting_types: Option<Vec<String>> = vesting_types.map(|vesting_types| {
+        vesting_types
+            .iter()
+            .map(|vesting_type| vesting_type.to_string())
+            .collect()
+    });
+
+    let mut release_due_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_RELEASE_DUE,
+        runtime_args! {
+            ARG_VESTING_TYPES => vesting_types,
+            ARG_BENEFICIARIES => beneficiaries,
+        },
+    );
+
+    if let Some(block_time) = block_time {
+        release_due_request = release_due_request.with_block_time(block_time * 1000)
+    }
+
+    builder.exec(release_due_request.build())
+}
+
+pub fn cowl_vesting_add_vesting_address<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    vesting_type: VestingType,
+    vesting_address_key: Key,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let add_vesting_address_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_ADD_VESTING_ADDRESS,
+        runtime_args! {
+            ARG_VESTING_TYPE => vesting_type.to_string(),
+            ARG_VESTING_ADDRESS => vesting_address_key,
+        },
+    )
+    .build();
+    builder.exec(add_vesting_address_request)
+}
+
+pub fn cowl_vesting_remove_vesting_address<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    vesting_type: VestingType,
+    vesting_address_key: Key,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let remove_vesting_address_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_REMOVE_VESTING_ADDRESS,
+        runtime_args! {
+            ARG_VESTING_TYPE => vesting_type.to_string(),
+            ARG_VESTING_ADDRESS => vesting_address_key,
+        },
+    )
+    .build();
+    builder.exec(remove_vesting_address_request)
+}
+
+pub fn cowl_cep18_token_increase_allowance<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_cep18_token_contract_hash: &'a ContractHash,
+    owner: &AccountHash,
+    spender: Key,
+    amount: U256,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let increase_allowance_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *owner,
+        *cowl_cep18_token_contract_hash,
+        ENTRY_POINT_INCREASE_ALLOWANCE,
+        runtime_args! {
+            ARG_SPENDER => spender,
+            ARG_AMOUNT => amount,
+        },
+    )
+    .build();
+    builder.exec(increase_allowance_request)
+}
+
+#[derive(Default)]
 pub struct SecurityLists {
     pub admin_list: Option<Vec<Key>>,
+    pub pauser_list: Option<Vec<Key>>,
+    pub schedule_manager_list: Option<Vec<Key>>,
+    pub keeper_list: Option<Vec<Key>>,
     pub none_list: Option<Vec<Key>>,
 }
 
@@ -308,6 +729,9 @@ pub fn cowl_vesting_change_security<'a>(
 ) -> &'a mut InMemoryWasmTestBuilder {
     let SecurityLists {
         admin_list,
+        pauser_list,
+        schedule_manager_list,
+        keeper_list,
         none_list,
     } = security_lists;
 
@@ -317,6 +741,9 @@ pub fn cowl_vesting_change_security<'a>(
         ENTRY_POINT_CHANGE_SECURITY,
         runtime_args! {
             ADMIN_LIST => admin_list.unwrap_or_default(),
+            PAUSER_LIST => pauser_list.unwrap_or_default(),
+            SCHEDULE_MANAGER_LIST => schedule_manager_list.unwrap_or_default(),
+            KEEPER_LIST => keeper_list.unwrap_or_default(),
             NONE_LIST => none_list.unwrap_or_default(),
         },
     )
@@ -324,6 +751,67 @@ pub fn cowl_vesting_change_security<'a>(
     builder.exec(change_security_request)
 }
 
+pub fn cowl_vesting_propose_admin<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    admin: Key,
+    block_time: Option<u64>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let mut propose_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_PROPOSE_ADMIN,
+        runtime_args! {
+            ARG_ADMIN => admin,
+        },
+    );
+
+    if let Some(block_time) = block_time {
+        propose_admin_request = propose_admin_request.with_block_time(block_time * 1000)
+    }
+
+    builder.exec(propose_admin_request.build())
+}
+
+pub fn cowl_vesting_accept_admin<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    block_time: Option<u64>,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let mut accept_admin_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_ACCEPT_ADMIN,
+        runtime_args! {},
+    );
+
+    if let Some(block_time) = block_time {
+        accept_admin_request = accept_admin_request.with_block_time(block_time * 1000)
+    }
+
+    builder.exec(accept_admin_request.build())
+}
+
+pub fn cowl_vesting_cancel_admin_proposal<'a>(
+    builder: &'a mut InMemoryWasmTestBuilder,
+    cowl_vesting: &'a ContractHash,
+    sender: &AccountHash,
+    admin: Key,
+) -> &'a mut InMemoryWasmTestBuilder {
+    let cancel_admin_proposal_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *sender,
+        *cowl_vesting,
+        ENTRY_POINT_CANCEL_ADMIN_PROPOSAL,
+        runtime_args! {
+            ARG_ADMIN => admin,
+        },
+    )
+    .build();
+    builder.exec(cancel_admin_proposal_request)
+}
+
 fn merge_args(install_args: RuntimeArgs) -> RuntimeArgs {
     let mut merged_args = install_args;
 
@@ -8,6 +8,7 @@ use casper_engine_test_support::{
 use casper_event_standard::EVENTS_DICT;
 use casper_execution_engine::{
     core::{engine_state::Error as EngineStateError, execution},
+    shared::transform::Transform,
     storage::global_state::in_memory::InMemoryGlobalState,
 };
 use casper_types::{
@@ -20,8 +21,15 @@ use casper_types::{
     },
     ApiError, CLTyped, Key, PublicKey, RuntimeArgs, SecretKey,
 };
-use cowl_vesting::enums::VestingType;
-use std::fmt::Debug;
+use cowl_vesting::{
+    constants::{ARG_ADMIN_COUNT, DICT_VESTING_GRANTS, DICT_VESTING_RECORDS},
+    enums::VestingType,
+    vesting::{
+        get_default_vesting_configs, get_vesting_address_dictionary_key, get_vesting_grant_key,
+        VestingGrantRecord, VestingRecord,
+    },
+};
+use std::{collections::BTreeMap, fmt::Debug};
 
 pub fn assert_expected_error(actual_error: EngineStateError, error_code: u16, reason: &str) {
     let actual = format!("{actual_error:?}");
@@ -35,6 +43,17 @@ pub fn assert_expected_error(actual_error: EngineStateError, error_code: u16, re
     )
 }
 
+pub fn get_admin_count(builder: &WasmTestBuilder<InMemoryGlobalState>, contract_key: &Key) -> u32 {
+    builder
+        .query(None, *contract_key, &[ARG_ADMIN_COUNT.to_string()])
+        .expect("should have admin count")
+        .as_cl_value()
+        .expect("should be CLValue")
+        .to_owned()
+        .into_t()
+        .unwrap()
+}
+
 pub fn get_dictionary_value_from_key<T: CLTyped + FromBytes>(
     builder: &WasmTestBuilder<InMemoryGlobalState>,
     contract_key: &Key,
@@ -62,6 +81,85 @@ pub fn get_dictionary_value_from_key<T: CLTyped + FromBytes>(
         .unwrap()
 }
 
+pub fn get_vesting_record(
+    builder: &WasmTestBuilder<InMemoryGlobalState>,
+    contract_key: &Key,
+    vesting_type: VestingType,
+) -> VestingRecord {
+    get_dictionary_value_from_key(
+        builder,
+        contract_key,
+        DICT_VESTING_RECORDS,
+        &vesting_type.to_string(),
+    )
+}
+
+pub fn get_vesting_grant_record(
+    builder: &WasmTestBuilder<InMemoryGlobalState>,
+    contract_key: &Key,
+    beneficiary: &Key,
+) -> VestingGrantRecord {
+    get_dictionary_value_from_key(
+        builder,
+        contract_key,
+        DICT_VESTING_GRANTS,
+        &get_vesting_grant_key(beneficiary),
+    )
+}
+
+/// Reads and writes made by the last execution in the dictionaries of the vesting contract, per
+/// dictionary name and item key. Items are looked up among the vesting types and the addresses
+/// given.
+pub fn get_vesting_dictionary_accesses(
+    builder: &WasmTestBuilder<InMemoryGlobalState>,
+    contract_key: &Key,
+    addresses: &[Key],
+) -> BTreeMap<(String, String), (usize, usize)> {
+    let dictionary_item_keys: Vec<String> = get_default_vesting_configs()
+        .iter()
+        .map(|vesting_config| vesting_config.vesting_type.to_string())
+        .chain(addresses.iter().map(get_vesting_address_dictionary_key))
+        .collect();
+
+    let named_keys = builder
+        .query(None, *contract_key, &[])
+        .expect("must have contract")
+        .as_contract()
+        .expect("must convert contract")
+        .named_keys()
+        .clone();
+
+    let mut dictionary_items: BTreeMap<Key, (String, String)> = BTreeMap::new();
+    for (dictionary_name, key) in named_keys.iter() {
+        if let Some(seed_uref) = key.as_uref() {
+            for item_key in dictionary_item_keys.iter() {
+                dictionary_items.insert(
+                    Key::dictionary(*seed_uref, item_key.as_bytes()),
+                    (dictionary_name.clone(), item_key.clone()),
+                );
+            }
+        }
+    }
+
+    let mut accesses = BTreeMap::new();
+    for exec_result in builder
+        .get_last_exec_results()
+        .expect("must have exec results")
+    {
+        for (key, transform) in exec_result.execution_journal().iter() {
+            if let Some(dictionary_item) = dictionary_items.get(key) {
+                let (reads, writes) = accesses.entry(dictionary_item.clone()).or_insert((0, 0));
+                match transform {
+                    Transform::Identity => *reads += 1,
+                    Transform::Write(_) => *writes += 1,
+                    _ => {}
+                }
+            }
+        }
+    }
+    accesses
+}
+
 pub fn get_event<T: FromBytes + CLTyped + Debug>(
     builder: &WasmTestBuilder<InMemoryGlobalState>,
     contract_key: &Key,
@@ -0,0 +1,362 @@
+use crate::utility::{
+    constants::{ACCOUNT_USER_1, ACCOUNT_USER_2, VESTING_CONTRACT_PACKAGE_HASH_KEY_NAME},
+    installer_request_builders::{
+        cowl_cep18_token_increase_allowance, cowl_cep18_token_transfer,
+        cowl_vesting_accept_vesting_address, cowl_vesting_change_vesting_address, setup,
+        TestContext,
+    },
+    support::{
+        assert_expected_error, get_account_for_vesting, get_dictionary_value_from_key, get_event,
+        get_vesting_record,
+    },
+};
+use casper_engine_test_support::{InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{Key, U256};
+use cowl_vesting::{
+    constants::{DICT_VESTING_ADDRESSES, MONTH_IN_SECONDS},
+    enums::VestingType,
+    error::VestingError,
+    events::{VestingAddressChange, VestingAddressProposal},
+    vesting::{get_vesting_address_dictionary_key, VestingAddress},
+};
+
+fn get_vesting_contract_package_key(builder: &InMemoryWasmTestBuilder) -> Key {
+    *builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(VESTING_CONTRACT_PACKAGE_HASH_KEY_NAME)
+        .expect("should have package hash")
+}
+
+#[test]
+fn should_move_vesting_schedule_to_accepted_address() {
+    let vesting_type = VestingType::Community;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let previous_vesting_account = *test_accounts
+        .get(&get_account_for_vesting(vesting_type))
+        .unwrap();
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+
+    let vesting_amount: U256 = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_amount;
+
+    cowl_vesting_change_vesting_address(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Key::from(account_user_1),
+    )
+    .expect_success()
+    .commit();
+
+    let expected_event = VestingAddressProposal::new(
+        Key::from(*DEFAULT_ACCOUNT_ADDR),
+        vesting_type.to_string(),
+        Key::from(account_user_1),
+    );
+    let actual_event: VestingAddressProposal =
+        get_event(&builder, &cowl_vesting_contract_hash.into(), 0);
+    assert_eq!(actual_event, expected_event);
+
+    // Only the proposed address can accept the change
+    cowl_vesting_accept_vesting_address(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_2,
+        vesting_type,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InsufficientRights as u16,
+        "only the proposed address can accept the change",
+    );
+
+    // The current address approves the move of part of its balance only
+    let moved_amount = vesting_amount / 2;
+
+    let cowl_vesting_contract_package_key = get_vesting_contract_package_key(&builder);
+
+    cowl_cep18_token_increase_allowance(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &previous_vesting_account,
+        cowl_vesting_contract_package_key,
+        moved_amount,
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_accept_vesting_address(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        vesting_type,
+    )
+    .expect_success()
+    .commit();
+
+    let actual_vesting_address_key: Key = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_info
+    .maybe_vesting_address_key
+    .unwrap();
+    assert_eq!(actual_vesting_address_key, Key::from(account_user_1));
+
+    // The address lookup follows the schedule
+    let actual_vesting_address: Option<VestingAddress> = get_dictionary_value_from_key(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        DICT_VESTING_ADDRESSES,
+        &get_vesting_address_dictionary_key(&Key::from(account_user_1)),
+    );
+    assert_eq!(
+        actual_vesting_address,
+        Some(VestingAddress::Type(vesting_type))
+    );
+
+    let previous_vesting_address: Option<VestingAddress> = get_dictionary_value_from_key(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        DICT_VESTING_ADDRESSES,
+        &get_vesting_address_dictionary_key(&Key::from(previous_vesting_account)),
+    );
+    assert_eq!(
+        previous_vesting_address,
+        Some(VestingAddress::Previous(vesting_type))
+    );
+
+    let expected_event = VestingAddressChange::new(
+        vesting_type.to_string(),
+        Key::from(previous_vesting_account),
+        Key::from(account_user_1),
+        moved_amount,
+    );
+    // The moved balance records a CheckTransfer event first
+    let actual_event: VestingAddressChange =
+        get_event(&builder, &cowl_vesting_contract_hash.into(), 2);
+    assert_eq!(actual_event, expected_event);
+
+    // The previous address keeps the balance it did not approve and can only move it to the new
+    // address
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &previous_vesting_account,
+        U256::one(),
+        &account_user_2,
+        None,
+    )
+    .expect_failure();
+
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &previous_vesting_account,
+        vesting_amount - moved_amount,
+        &account_user_1,
+        None,
+    )
+    .expect_success()
+    .commit();
+
+    // The schedule now locks the new address
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &account_user_1,
+        vesting_amount,
+        &account_user_2,
+        Some(MONTH_IN_SECONDS),
+    )
+    .expect_failure();
+
+    // The previous address has no balance left
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &previous_vesting_account,
+        U256::one(),
+        &account_user_1,
+        None,
+    )
+    .expect_failure();
+}
+
+#[test]
+fn should_move_approved_balance_and_free_previous_address() {
+    let vesting_type = VestingType::Community;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            cowl_cep18_token_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let previous_vesting_account = *test_accounts
+        .get(&get_account_for_vesting(vesting_type))
+        .unwrap();
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let account_user_2 = *test_accounts.get(&ACCOUNT_USER_2).unwrap();
+
+    let vesting_amount: U256 = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_amount;
+
+    cowl_vesting_change_vesting_address(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Key::from(account_user_1),
+    )
+    .expect_success()
+    .commit();
+
+    let cowl_vesting_contract_package_key = get_vesting_contract_package_key(&builder);
+
+    cowl_cep18_token_increase_allowance(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &previous_vesting_account,
+        cowl_vesting_contract_package_key,
+        vesting_amount,
+    )
+    .expect_success()
+    .commit();
+
+    cowl_vesting_accept_vesting_address(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        vesting_type,
+    )
+    .expect_success()
+    .commit();
+
+    let expected_event = VestingAddressChange::new(
+        vesting_type.to_string(),
+        Key::from(previous_vesting_account),
+        Key::from(account_user_1),
+        vesting_amount,
+    );
+    // The moved balance records a CheckTransfer event first
+    let actual_event: VestingAddressChange =
+        get_event(&builder, &cowl_vesting_contract_hash.into(), 2);
+    assert_eq!(actual_event, expected_event);
+
+    // Nothing is left behind, the previous address no longer belongs to the vesting type
+    let previous_vesting_address: Option<VestingAddress> = get_dictionary_value_from_key(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        DICT_VESTING_ADDRESSES,
+        &get_vesting_address_dictionary_key(&Key::from(previous_vesting_account)),
+    );
+    assert_eq!(previous_vesting_address, None);
+
+    // The whole allocation is locked by the schedule on the new address
+    cowl_cep18_token_transfer(
+        &mut builder,
+        &cowl_cep18_token_contract_hash,
+        &account_user_1,
+        vesting_amount,
+        &account_user_2,
+        Some(MONTH_IN_SECONDS),
+    )
+    .expect_failure();
+}
+
+#[test]
+fn should_not_change_vesting_address_without_admin_rights_or_to_a_vesting_address() {
+    let vesting_type = VestingType::Community;
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ref test_accounts,
+            ..
+        },
+    ) = setup();
+
+    let account_user_1 = *test_accounts.get(&ACCOUNT_USER_1).unwrap();
+    let treasury_account = *test_accounts
+        .get(&get_account_for_vesting(VestingType::Treasury))
+        .unwrap();
+
+    cowl_vesting_change_vesting_address(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        vesting_type,
+        Key::from(account_user_1),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InsufficientRights as u16,
+        "only admins can change a vesting address",
+    );
+
+    cowl_vesting_change_vesting_address(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Key::from(treasury_account),
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::InvalidVestingAddress as u16,
+        "should not move a schedule to an address holding one",
+    );
+
+    cowl_vesting_accept_vesting_address(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &account_user_1,
+        vesting_type,
+    )
+    .expect_failure();
+
+    let error = builder.get_error().expect("must have error");
+    assert_expected_error(
+        error,
+        VestingError::MissingPendingVestingAddress as u16,
+        "should not accept an address change that was not proposed",
+    );
+}
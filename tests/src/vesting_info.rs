@@ -1,6 +1,13 @@
 use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
 use casper_types::Key;
-use cowl_vesting::{constants::DICT_VESTING_INFO, enums::VestingType, vesting::VestingInfo};
+use cowl_vesting::{
+    constants::{
+        CLIFF_CONTRIBUTOR_VESTING, CLIFF_DEVELOPMENT_VESTING, DICT_VESTING_INFO,
+        PERIOD_CONTRIBUTOR_VESTING, PERIOD_TREASURY_VESTING,
+    },
+    enums::VestingType,
+    vesting::VestingInfo,
+};
 
 use crate::utility::{
     installer_request_builders::{cowl_vesting_vesting_info, setup, TestContext},
@@ -36,6 +43,7 @@ fn should_get_vesting_treasury_info() {
         &dictionary_key,
     );
 
+    assert_eq!(vesting_info.vesting_period, PERIOD_TREASURY_VESTING);
     dbg!(vesting_info);
 }
 
@@ -68,6 +76,8 @@ fn should_get_vesting_contributor_info() {
         &dictionary_key,
     );
 
+    assert_eq!(vesting_info.vesting_period, PERIOD_CONTRIBUTOR_VESTING);
+    assert_eq!(vesting_info.cliff_duration, CLIFF_CONTRIBUTOR_VESTING);
     dbg!(vesting_info);
 }
 
@@ -100,6 +110,7 @@ fn should_get_vesting_development_info() {
         &dictionary_key,
     );
 
+    assert_eq!(vesting_info.cliff_duration, CLIFF_DEVELOPMENT_VESTING);
     dbg!(vesting_info);
 }
 
@@ -1,16 +1,20 @@
 use crate::utility::{
-    installer_request_builders::{cowl_vesting_vesting_status, setup, TestContext},
-    support::get_dictionary_value_from_key,
+    installer_request_builders::{
+        cowl_vesting_vesting_status, setup, setup_with_args, TestContext,
+    },
+    support::get_vesting_record,
 };
-use casper_engine_test_support::DEFAULT_ACCOUNT_ADDR;
-use casper_types::{Key, U256};
+use casper_engine_test_support::{InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR};
+use casper_types::{runtime_args, Key, RuntimeArgs, U256};
 use cowl_vesting::{
     constants::{
-        DICT_VESTING_STATUS, DURATION_COMMUNITY_VESTING, DURATION_CONTRIBUTOR_VESTING,
-        DURATION_DEVELOPMENT_VESTING, DURATION_TREASURY_VESTING,
+        ARG_VESTING_ALLOCATIONS, BASIS_POINTS_DENOMINATOR, CLIFF_CONTRIBUTOR_VESTING,
+        CLIFF_DEVELOPMENT_VESTING, DURATION_COMMUNITY_VESTING, DURATION_CONTRIBUTOR_VESTING,
+        DURATION_DEVELOPMENT_VESTING, DURATION_TREASURY_VESTING, MONTH_IN_SECONDS,
+        PERIOD_CONTRIBUTOR_VESTING, PERIOD_TREASURY_VESTING,
     },
-    enums::VestingType,
-    vesting::VestingStatus,
+    enums::{PeriodMode, ReleaseCurve, VestingType},
+    vesting::{get_default_vesting_configs, VestingStatus, VestingTranche},
 };
 use std::time::Duration;
 
@@ -35,13 +39,12 @@ fn should_get_vesting_treasury_status() {
     );
     vesting_vesting_status_call.expect_success().commit();
 
-    let dictionary_key = vesting_type.to_string();
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &dictionary_key.to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
     assert!(!vesting_status.is_fully_vested);
     assert_eq!(vesting_status.vested_amount, U256::zero());
     assert_eq!(vesting_status.vesting_type, vesting_type);
@@ -51,6 +54,7 @@ fn should_get_vesting_treasury_status() {
     );
     assert!(vesting_status.time_until_next_release > Duration::ZERO);
     assert!(vesting_status.release_amount_per_period > U256::zero());
+    assert_eq!(vesting_status.cliff_end, vesting_status.start_time);
     dbg!(vesting_status);
 }
 
@@ -75,13 +79,12 @@ fn should_get_vesting_contributor_status() {
     );
     vesting_vesting_status_call.expect_success().commit();
 
-    let dictionary_key = vesting_type.to_string();
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &dictionary_key.to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
     assert!(!vesting_status.is_fully_vested);
     assert_eq!(vesting_status.vested_amount, U256::zero());
     assert_eq!(vesting_status.vesting_type, vesting_type);
@@ -91,6 +94,11 @@ fn should_get_vesting_contributor_status() {
     );
     assert!(vesting_status.time_until_next_release > Duration::ZERO);
     assert!(vesting_status.release_amount_per_period > U256::zero());
+    assert_eq!(
+        vesting_status.cliff_end,
+        vesting_status.start_time
+            + Duration::from_secs(CLIFF_CONTRIBUTOR_VESTING.unwrap().whole_seconds() as u64)
+    );
     dbg!(vesting_status);
 }
 
@@ -115,13 +123,12 @@ fn should_get_vesting_development_status() {
     );
     vesting_vesting_status_call.expect_success().commit();
 
-    let dictionary_key = vesting_type.to_string();
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &dictionary_key.to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
     assert!(!vesting_status.is_fully_vested);
     assert_eq!(vesting_status.vested_amount, U256::zero());
     assert_eq!(vesting_status.vesting_type, vesting_type);
@@ -131,9 +138,82 @@ fn should_get_vesting_development_status() {
     );
     assert!(vesting_status.time_until_next_release > Duration::ZERO);
     assert!(vesting_status.release_amount_per_period > U256::zero());
+    assert_eq!(
+        vesting_status.cliff_end,
+        vesting_status.start_time
+            + Duration::from_secs(CLIFF_DEVELOPMENT_VESTING.unwrap().whole_seconds() as u64)
+    );
     dbg!(vesting_status);
 }
 
+#[test]
+fn should_get_vesting_contributor_status_at_cliff_end() {
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ..
+        },
+    ) = setup();
+
+    let vesting_type = VestingType::Contributor;
+    let cliff_end = CLIFF_CONTRIBUTOR_VESTING.unwrap().whole_seconds() as u64;
+    let cliff_periods = cliff_end / PERIOD_CONTRIBUTOR_VESTING.whole_seconds() as u64;
+
+    // Nothing is released before the cliff, even though periods have elapsed
+    cowl_vesting_vesting_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Some(cliff_end - 1),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_status: VestingStatus = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_status;
+
+    assert_eq!(vesting_status.vested_amount, U256::zero());
+    assert_eq!(vesting_status.available_for_release_amount, U256::zero());
+    assert_eq!(
+        vesting_status.time_until_next_release,
+        Duration::from_secs(1)
+    );
+
+    // The periods accrued during the cliff unlock at once when it ends
+    cowl_vesting_vesting_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Some(cliff_end),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_status: VestingStatus = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_status;
+
+    let accrued_amount = vesting_status.release_amount_per_period * U256::from(cliff_periods);
+
+    assert_eq!(vesting_status.elapsed_periods, U256::from(cliff_periods));
+    assert_eq!(vesting_status.vested_amount, accrued_amount);
+    assert_eq!(vesting_status.available_for_release_amount, accrued_amount);
+    assert_eq!(
+        vesting_status.time_until_next_release,
+        Duration::from_secs(PERIOD_CONTRIBUTOR_VESTING.whole_seconds() as u64)
+    );
+}
+
 #[test]
 fn should_get_vesting_liquidity_status() {
     let (
@@ -155,13 +235,12 @@ fn should_get_vesting_liquidity_status() {
     );
     vesting_vesting_status_call.expect_success().commit();
 
-    let dictionary_key = vesting_type.to_string();
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &dictionary_key.to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(vesting_status.is_fully_vested);
     assert_eq!(vesting_status.vested_amount, U256::zero());
@@ -193,13 +272,12 @@ fn should_get_vesting_community_status() {
     );
     vesting_vesting_status_call.expect_success().commit();
 
-    let dictionary_key = vesting_type.to_string();
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &dictionary_key.to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(!vesting_status.is_fully_vested);
     assert_eq!(vesting_status.vested_amount, U256::zero());
@@ -234,13 +312,12 @@ fn should_get_vesting_staking_status() {
     );
     vesting_vesting_status_call.expect_success().commit();
 
-    let dictionary_key = vesting_type.to_string();
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &dictionary_key.to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
     assert!(vesting_status.is_fully_vested);
     assert_eq!(vesting_status.vested_amount, U256::zero());
     assert_eq!(vesting_status.vesting_type, vesting_type);
@@ -273,13 +350,12 @@ fn should_get_vesting_contributor_status_half_year() {
     );
     vesting_vesting_status_call.expect_success().commit();
 
-    let dictionary_key = vesting_type.to_string();
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &dictionary_key.to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(!vesting_status.is_fully_vested);
     assert!(vesting_status.vested_amount > U256::zero());
@@ -314,13 +390,12 @@ fn should_get_vesting_contributor_status_one_and_half_year() {
     );
     vesting_vesting_status_call.expect_success().commit();
 
-    let dictionary_key = vesting_type.to_string();
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &dictionary_key.to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(vesting_status.is_fully_vested);
     assert!(vesting_status.vested_amount > U256::zero());
@@ -357,13 +432,12 @@ fn should_get_vesting_development_status_half_year() {
     );
     vesting_vesting_status_call.expect_success().commit();
 
-    let dictionary_key = vesting_type.to_string();
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &dictionary_key.to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(!vesting_status.is_fully_vested);
     assert!(vesting_status.vested_amount > U256::zero());
@@ -398,13 +472,12 @@ fn should_get_vesting_development_status_one_and_half_year() {
     );
     vesting_vesting_status_call.expect_success().commit();
 
-    let dictionary_key = vesting_type.to_string();
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &dictionary_key.to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(vesting_status.is_fully_vested);
     assert!(vesting_status.vested_amount > U256::zero());
@@ -441,13 +514,12 @@ fn should_get_vesting_treasury_status_two_year() {
     );
     vesting_vesting_status_call.expect_success().commit();
 
-    let dictionary_key = vesting_type.to_string();
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &dictionary_key.to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(!vesting_status.is_fully_vested);
     assert!(vesting_status.vested_amount > U256::zero());
@@ -482,13 +554,12 @@ fn should_get_vesting_treasury_status_six_year() {
     );
     vesting_vesting_status_call.expect_success().commit();
 
-    let dictionary_key = vesting_type.to_string();
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &dictionary_key.to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(vesting_status.is_fully_vested);
     assert!(vesting_status.vested_amount > U256::zero());
@@ -525,13 +596,12 @@ fn should_get_vesting_community_status_two_year() {
     );
     vesting_vesting_status_call.expect_success().commit();
 
-    let dictionary_key = vesting_type.to_string();
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &dictionary_key.to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(!vesting_status.is_fully_vested);
     assert!(vesting_status.vested_amount > U256::zero());
@@ -566,13 +636,12 @@ fn should_get_vesting_community_status_six_year() {
     );
     vesting_vesting_status_call.expect_success().commit();
 
-    let dictionary_key = vesting_type.to_string();
-    let vesting_status: VestingStatus = get_dictionary_value_from_key(
+    let vesting_status: VestingStatus = get_vesting_record(
         &builder,
         &Key::from(cowl_vesting_contract_hash),
-        DICT_VESTING_STATUS,
-        &dictionary_key.to_owned(),
-    );
+        vesting_type,
+    )
+    .vesting_status;
 
     assert!(vesting_status.is_fully_vested);
     assert!(vesting_status.vested_amount > U256::zero());
@@ -585,3 +654,429 @@ fn should_get_vesting_community_status_six_year() {
     //  assert_eq!(vesting_status.release_amount_per_period, U256::zero());
     dbg!(vesting_status);
 }
+
+#[test]
+fn should_get_vesting_community_status_on_calendar_months() {
+    let vesting_type = VestingType::Community;
+
+    let mut vesting_configs = get_default_vesting_configs();
+    for vesting_config in vesting_configs.iter_mut() {
+        if vesting_config.vesting_type == vesting_type {
+            vesting_config.period_mode = PeriodMode::Calendar;
+        }
+    }
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_VESTING_ALLOCATIONS => vesting_configs,
+        },
+        None,
+    );
+
+    let day_in_seconds = 24 * 60 * 60;
+
+    // Start time is 1970-01-01, the first release happens on 1970-02-01
+    let first_release_time = 31 * day_in_seconds;
+
+    cowl_vesting_vesting_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Some(first_release_time - 1),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_status: VestingStatus = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_status;
+
+    assert_eq!(vesting_status.elapsed_periods, U256::zero());
+    assert_eq!(vesting_status.vested_amount, U256::zero());
+    assert_eq!(
+        vesting_status.time_until_next_release,
+        Duration::from_secs(1)
+    );
+
+    cowl_vesting_vesting_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Some(first_release_time),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_status: VestingStatus = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_status;
+
+    assert_eq!(vesting_status.elapsed_periods, U256::one());
+    assert_eq!(
+        vesting_status.vested_amount,
+        vesting_status.release_amount_per_period
+    );
+    // February 1970 has 28 days, the second release happens on 1970-03-01
+    assert_eq!(
+        vesting_status.time_until_next_release,
+        Duration::from_secs(28 * day_in_seconds)
+    );
+}
+
+fn setup_with_release_curve(
+    vesting_type: VestingType,
+    release_curve: ReleaseCurve,
+) -> (InMemoryWasmTestBuilder, TestContext) {
+    let mut vesting_configs = get_default_vesting_configs();
+    for vesting_config in vesting_configs.iter_mut() {
+        if vesting_config.vesting_type == vesting_type {
+            vesting_config.release_curve = release_curve;
+        }
+    }
+
+    setup_with_args(
+        runtime_args! {
+            ARG_VESTING_ALLOCATIONS => vesting_configs,
+        },
+        None,
+    )
+}
+
+#[test]
+fn should_get_vesting_contributor_status_on_continuous_curve() {
+    let vesting_type = VestingType::Contributor;
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ..
+        },
+    ) = setup_with_release_curve(vesting_type, ReleaseCurve::Continuous);
+
+    // Half a period past the cliff, the stepwise curve would still be on the last period
+    let vesting_duration = DURATION_CONTRIBUTOR_VESTING.unwrap().whole_seconds() as u64;
+    let cliff_end = CLIFF_CONTRIBUTOR_VESTING.unwrap().whole_seconds() as u64;
+    let test_duration = cliff_end + MONTH_IN_SECONDS / 2;
+
+    cowl_vesting_vesting_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Some(test_duration),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_status: VestingStatus = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_status;
+
+    assert!(!vesting_status.is_fully_vested);
+    assert_eq!(
+        vesting_status.elapsed_periods,
+        U256::from(cliff_end / MONTH_IN_SECONDS)
+    );
+    assert_eq!(
+        vesting_status.vested_amount,
+        vesting_status.total_amount * U256::from(test_duration) / U256::from(vesting_duration)
+    );
+    assert_eq!(
+        vesting_status.available_for_release_amount,
+        vesting_status.vested_amount
+    );
+
+    cowl_vesting_vesting_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Some(vesting_duration),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_status: VestingStatus = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_status;
+
+    assert!(vesting_status.is_fully_vested);
+    assert_eq!(vesting_status.vested_amount, vesting_status.total_amount);
+}
+
+#[test]
+fn should_get_vesting_community_status_on_back_loaded_curve() {
+    let vesting_type = VestingType::Community;
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ..
+        },
+    ) = setup_with_release_curve(vesting_type, ReleaseCurve::BackLoaded);
+
+    let vesting_duration = DURATION_COMMUNITY_VESTING.unwrap().whole_seconds() as u64;
+
+    cowl_vesting_vesting_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Some(vesting_duration / 2),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_status: VestingStatus = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_status;
+
+    // Half way through the schedule only a quarter of the allocation is vested
+    assert!(!vesting_status.is_fully_vested);
+    assert_eq!(
+        vesting_status.vested_amount,
+        vesting_status.total_amount / U256::from(4)
+    );
+    assert_eq!(
+        vesting_status.available_for_release_amount,
+        vesting_status.vested_amount
+    );
+
+    cowl_vesting_vesting_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Some(vesting_duration),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_status: VestingStatus = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_status;
+
+    assert!(vesting_status.is_fully_vested);
+    assert_eq!(vesting_status.vested_amount, vesting_status.total_amount);
+}
+
+#[test]
+fn should_get_vesting_community_status_on_tranches() {
+    let vesting_type = VestingType::Community;
+    let month = time::Duration::seconds(MONTH_IN_SECONDS as i64);
+
+    // 10% at listing, 25% after three months, then quarterly until the end of the first year
+    let tranches = vec![
+        VestingTranche {
+            release_offset: time::Duration::ZERO,
+            cumulative_share: 1_000,
+        },
+        VestingTranche {
+            release_offset: month * 3,
+            cumulative_share: 2_500,
+        },
+        VestingTranche {
+            release_offset: month * 6,
+            cumulative_share: 5_000,
+        },
+        VestingTranche {
+            release_offset: month * 9,
+            cumulative_share: 7_500,
+        },
+        VestingTranche {
+            release_offset: month * 12,
+            cumulative_share: BASIS_POINTS_DENOMINATOR,
+        },
+    ];
+
+    let mut vesting_configs = get_default_vesting_configs();
+    for vesting_config in vesting_configs.iter_mut() {
+        if vesting_config.vesting_type == vesting_type {
+            vesting_config.release_curve = ReleaseCurve::Tranches;
+            vesting_config.vesting_duration = Some(month * 12);
+            vesting_config.tranches = tranches.clone();
+        }
+    }
+
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ..
+        },
+    ) = setup_with_args(
+        runtime_args! {
+            ARG_VESTING_ALLOCATIONS => vesting_configs,
+        },
+        None,
+    );
+
+    let vesting_status: VestingStatus = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_status;
+
+    let total_amount = vesting_status.total_amount;
+    let share_of_total =
+        |share: u16| total_amount * U256::from(share) / U256::from(BASIS_POINTS_DENOMINATOR);
+
+    // The listing tranche is available right away, the next one in three months
+    assert_eq!(vesting_status.vested_amount, share_of_total(1_000));
+    assert_eq!(vesting_status.elapsed_periods, U256::one());
+    assert_eq!(
+        vesting_status.time_until_next_release,
+        Duration::from_secs(3 * MONTH_IN_SECONDS)
+    );
+    assert_eq!(
+        vesting_status.release_amount_per_period,
+        share_of_total(2_500) - share_of_total(1_000)
+    );
+
+    cowl_vesting_vesting_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Some(4 * MONTH_IN_SECONDS),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_status: VestingStatus = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_status;
+
+    assert_eq!(vesting_status.vested_amount, share_of_total(2_500));
+    assert_eq!(
+        vesting_status.available_for_release_amount,
+        share_of_total(2_500)
+    );
+    assert_eq!(vesting_status.elapsed_periods, U256::from(2));
+    assert_eq!(
+        vesting_status.time_until_next_release,
+        Duration::from_secs(2 * MONTH_IN_SECONDS)
+    );
+
+    cowl_vesting_vesting_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Some(12 * MONTH_IN_SECONDS),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_status: VestingStatus = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_status;
+
+    assert!(vesting_status.is_fully_vested);
+    assert_eq!(vesting_status.vested_amount, total_amount);
+    assert_eq!(vesting_status.time_until_next_release, Duration::ZERO);
+}
+
+#[test]
+fn should_get_vesting_treasury_status_on_quarterly_periods() {
+    let (
+        mut builder,
+        TestContext {
+            cowl_vesting_contract_hash,
+            ..
+        },
+    ) = setup();
+
+    let vesting_type = VestingType::Treasury;
+    let vesting_period = PERIOD_TREASURY_VESTING.whole_seconds() as u64;
+    let total_periods = DURATION_TREASURY_VESTING.unwrap().whole_seconds() as u64 / vesting_period;
+
+    // One month in, the first quarterly release is still two months away
+    cowl_vesting_vesting_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Some(MONTH_IN_SECONDS),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_status: VestingStatus = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_status;
+
+    assert_eq!(vesting_status.elapsed_periods, U256::zero());
+    assert_eq!(vesting_status.vested_amount, U256::zero());
+    assert_eq!(
+        vesting_status.time_until_next_release,
+        Duration::from_secs(vesting_period - MONTH_IN_SECONDS)
+    );
+    assert_eq!(
+        vesting_status.release_amount_per_period,
+        vesting_status.total_amount / U256::from(total_periods)
+    );
+
+    cowl_vesting_vesting_status(
+        &mut builder,
+        &cowl_vesting_contract_hash,
+        &DEFAULT_ACCOUNT_ADDR,
+        vesting_type,
+        Some(vesting_period),
+    )
+    .expect_success()
+    .commit();
+
+    let vesting_status: VestingStatus = get_vesting_record(
+        &builder,
+        &Key::from(cowl_vesting_contract_hash),
+        vesting_type,
+    )
+    .vesting_status;
+
+    assert_eq!(vesting_status.elapsed_periods, U256::one());
+    assert_eq!(
+        vesting_status.vested_amount,
+        vesting_status.release_amount_per_period
+    );
+    assert_eq!(
+        vesting_status.time_until_next_release,
+        Duration::from_secs(vesting_period)
+    );
+}